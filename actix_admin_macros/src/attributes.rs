@@ -3,7 +3,7 @@
 //! Ported from `bae` on `syn 1` to `darling` on `syn 2`.
 
 pub mod derive_attr {
-    use darling::FromField;
+    use darling::{FromField, FromVariant};
 
     /// Parsed contents of `#[actix_admin(...)]` on a struct field.
     ///
@@ -58,6 +58,50 @@ pub mod derive_attr {
         pub tenant_ref: Option<()>,
         #[darling(default)]
         pub use_tom_select_callback: Option<()>,
+        #[darling(default)]
+        pub filter: Option<()>,
+        #[darling(default)]
+        pub lookup: Option<()>,
+        #[darling(default)]
+        pub lookup_label: Option<()>,
+        #[darling(default)]
+        pub json: Option<()>,
+        #[darling(default)]
+        pub json_schema: Option<syn::LitStr>,
+        #[darling(default)]
+        pub password: Option<()>,
+        #[darling(default)]
+        pub template: Option<syn::LitStr>,
+        #[darling(default)]
+        pub list_template: Option<syn::LitStr>,
+        #[darling(default)]
+        pub show_template: Option<syn::LitStr>,
+        #[darling(default)]
+        pub form_template: Option<syn::LitStr>,
+        #[darling(default)]
+        pub tab: Option<syn::LitStr>,
+        #[darling(default)]
+        pub fieldset: Option<syn::LitStr>,
+        #[darling(default)]
+        pub form_row: Option<syn::LitStr>,
+        #[darling(default)]
+        pub default_value: Option<syn::LitStr>,
+        #[darling(default)]
+        pub visible_when: Option<syn::LitStr>,
+        #[darling(default)]
+        pub required_when: Option<syn::LitStr>,
+        #[darling(default)]
+        pub min_length: Option<syn::LitInt>,
+        #[darling(default)]
+        pub max_length: Option<syn::LitInt>,
+        #[darling(default)]
+        pub min: Option<syn::Lit>,
+        #[darling(default)]
+        pub max: Option<syn::Lit>,
+        #[darling(default)]
+        pub regex: Option<syn::LitStr>,
+        #[darling(default)]
+        pub one_of: Option<syn::LitStr>,
 
         // ---- required by `FromField` (not used by us) ----
         #[allow(dead_code)]
@@ -88,4 +132,19 @@ pub mod derive_attr {
             Ok(Some(<Self as FromField>::from_field(&synthetic)?))
         }
     }
+
+    /// Parsed contents of `#[actix_admin(...)]` on an enum variant of a
+    /// `DeriveActixAdminEnumSelectList`.
+    #[derive(Debug, FromVariant, Clone)]
+    #[darling(attributes(actix_admin), forward_attrs(allow, doc, cfg))]
+    pub struct ActixAdminVariant {
+        #[darling(default)]
+        pub label: Option<syn::LitStr>,
+
+        // ---- required by `FromVariant` (not used by us) ----
+        #[allow(dead_code)]
+        pub ident: syn::Ident,
+        #[allow(dead_code)]
+        pub attrs: Vec<syn::Attribute>,
+    }
 }
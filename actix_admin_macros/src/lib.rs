@@ -9,7 +9,9 @@ mod struct_fields;
 use struct_fields::*;
 
 mod selectlist_fields;
-use selectlist_fields::{get_select_list_from_enum, get_select_list_from_model, get_select_lists};
+use selectlist_fields::{
+    get_filter_values, get_select_list_from_enum, get_select_list_from_model, get_select_lists,
+};
 
 mod attributes;
 mod model_fields;
@@ -36,6 +38,7 @@ pub fn derive_actix_admin(_input: proc_macro::TokenStream) -> proc_macro::TokenS
         use sea_orm::{
             ActiveValue::Set,
             ConnectOptions,
+            ConnectionTrait,
             DatabaseConnection,
             entity::*,
             query::*,
@@ -59,6 +62,7 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
     let has_searchable_fields = fields_searchable.len() > 0;
 
     let select_lists = get_select_lists(&fields);
+    let filter_values = get_filter_values(&fields);
 
     let tenant_ref_field = get_tenant_ref_field(&fields, false);
 
@@ -78,9 +82,19 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                     user_can_delete: None,
                     user_can_view_details: None,
                     user_can_export: None,
-                    default_show_aside: Entity::get_filter().len() > 0,
+                    default_show_aside: Entity::get_filter().len() > 0
+                        || Entity::get_fields().iter().any(|field| field.filter),
                     inline_edit: false,
+                    keyset_pagination: false,
+                    search_backend: ActixAdminSearchBackend::default(),
+                    search_by_relevance: false,
+                    filter_names: Entity::get_filter().into_iter().map(|f| f.name).collect(),
                     bulk_actions: Vec::new(),
+                    computed_columns: Vec::new(),
+                    collapsible_fieldsets: Vec::new(),
+                    field_defaults: HashMap::new(),
+                    non_copyable_fields: Vec::new(),
+                    duplicate_copies_files: false,
                 }
             }
         }
@@ -95,7 +109,11 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                 entities
             }
 
-            async fn validate_entity(model: &mut ActixAdminModel, db: &DatabaseConnection) {
+            async fn lookup(db: &DatabaseConnection, params: &ActixAdminLookupParams) -> Result<Vec<(String, String)>, ActixAdminError> {
+                Entity::lookup_model(db, params).await
+            }
+
+            async fn validate_entity(model: &mut ActixAdminModel, db: &DatabaseConnection, session: &Session, operation: &ActixAdminOperation) {
                 Entity::validate_model(model);
 
                 if !model.has_errors() {
@@ -104,14 +122,25 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                     model.custom_errors = custom_errors;
                 }
 
+                let async_errors = Entity::validate_async(db, session, operation, model).await;
+                for (field_name, error) in async_errors {
+                    if !model.errors.contains_key(&field_name) {
+                        model.custom_errors.entry(field_name).or_insert(error);
+                    }
+                }
+
                 if model.has_errors() {
-                    let mut model_entities = vec![model.clone()];
-                    Self::load_foreign_keys(&mut model_entities, db).await;
-                    model.fk_values = model_entities.pop().unwrap().fk_values;
+                    Self::load_fk_values(model, db).await;
                 }
             }
 
-            async fn create_entity(db: &DatabaseConnection, mut model: ActixAdminModel, tenant_ref: Option<i32>) -> Result<ActixAdminModel, ActixAdminError> {
+            async fn load_fk_values(model: &mut ActixAdminModel, db: &DatabaseConnection) {
+                let mut model_entities = vec![model.clone()];
+                Self::load_foreign_keys(&mut model_entities, db).await;
+                model.fk_values = model_entities.pop().unwrap().fk_values;
+            }
+
+            async fn create_entity<C: ConnectionTrait>(db: &C, mut model: ActixAdminModel, tenant_ref: Option<i32>) -> Result<ActixAdminModel, ActixAdminError> {
                 // Guard the infallible `ActiveModel::from` conversion below,
                 // which `.unwrap()`s each field. The route layer already
                 // validates, but a direct caller could pass a malformed model;
@@ -137,12 +166,24 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
             async fn get_viewmodel_filter(db: &DatabaseConnection) -> HashMap<String, ActixAdminViewModelFilter> {
                 let mut hashmap: HashMap<String, ActixAdminViewModelFilter> = HashMap::new();
 
+                // Filters generated from `#[actix_admin(filter)]` fields;
+                // registered filters of the same name replace them.
+                for field in Entity::get_fields().iter().filter(|field| field.filter) {
+                    let mut filter = ActixAdminViewModelFilter::from_field(field);
+                    filter.values = match field.field_name.as_str() {
+                        #(#filter_values)*
+                        _ => None,
+                    };
+                    hashmap.insert(filter.name.clone(), filter);
+                }
+
                 for filter in Entity::get_filter() {
                     hashmap.insert(
                         filter.name.to_string(),
                         ActixAdminViewModelFilter {
                             name: filter.name.to_string(),
                             value: None,
+                            selected: Vec::new(),
                             values: Entity::get_filter_values(&filter, db).await,
                             filter_type: Some(filter.filter_type),
                             foreign_key: filter.foreign_key.clone(),
@@ -155,7 +196,7 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                 hashmap
             }
 
-            async fn get_entity(db: &DatabaseConnection, id: Self::Id, tenant_ref: Option<i32>) -> Result<ActixAdminModel, ActixAdminError> {
+            async fn get_entity<C: ConnectionTrait>(db: &C, id: Self::Id, tenant_ref: Option<i32>) -> Result<ActixAdminModel, ActixAdminError> {
                 let mut query = Entity::find().filter(Column::#primary_key_column.eq(id));
 
                 #tenant_ref_field
@@ -177,10 +218,11 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                 }
             }
 
-            async fn edit_entity(db: &DatabaseConnection, id: Self::Id, mut model: ActixAdminModel, tenant_ref: Option<i32>) -> Result<ActixAdminModel, ActixAdminError> {
+            async fn edit_entity<C: ConnectionTrait>(db: &C, id: Self::Id, mut model: ActixAdminModel, tenant_ref: Option<i32>) -> Result<ActixAdminModel, ActixAdminError> {
                 // Guard the `.unwrap()`-based field assignments below against a
                 // malformed model passed by a direct caller that skipped the
                 // route-layer validation.
+                model.primary_key.get_or_insert_with(|| id.to_string());
                 Entity::validate_model(&mut model);
                 if model.has_errors() {
                     return Err(ActixAdminError {
@@ -209,7 +251,7 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                 }
             }
 
-            async fn delete_entity(db: &DatabaseConnection, id: Self::Id, tenant_ref: Option<i32>) -> Result<bool, ActixAdminError> {
+            async fn delete_entity<C: ConnectionTrait>(db: &C, id: Self::Id, tenant_ref: Option<i32>) -> Result<bool, ActixAdminError> {
                 let mut query = Entity::delete_many().filter(Column::#primary_key_column.eq(id));
 
                 #tenant_ref_field
@@ -226,7 +268,7 @@ pub fn derive_actix_admin_view_model(input: proc_macro::TokenStream) -> proc_mac
                 }
             }
 
-            async fn delete_entities(db: &DatabaseConnection, ids: &[Self::Id], tenant_ref: Option<i32>) -> Result<u64, ActixAdminError> {
+            async fn delete_entities<C: ConnectionTrait>(db: &C, ids: &[Self::Id], tenant_ref: Option<i32>) -> Result<u64, ActixAdminError> {
                 if ids.is_empty() {
                     return Ok(0);
                 }
@@ -298,10 +340,13 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
         get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.is_option() });
     let fields_for_create_model = get_fields_for_create_model(&fields);
     let fields_for_from_model = get_fields_for_from_model(&fields);
+    let fields_for_enum_labels = get_fields_for_enum_labels(&fields);
     let fields_for_load_foreign_key = get_fields_for_load_foreign_key(&fields);
     let field_for_primary_key = get_field_for_primary_key(&fields);
     let fields_for_validate_model = get_fields_for_validate_model(&fields);
     let primary_key_column = get_primary_key_column_ident(&fields);
+    let primary_key_field_name = get_primary_key_field_name(&fields);
+    let primary_key_type = get_primary_key_type(&fields);
     let fields_type_path = get_fields_as_tokenstream(&fields, |model_field| -> String {
         model_field.get_type_path_string()
     });
@@ -320,6 +365,30 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
         get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.wysiwyg });
     let fields_readonly =
         get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.readonly });
+    let fields_json =
+        get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.is_json() });
+    let fields_json_schema = get_fields_json_schema(&fields);
+    let fields_password =
+        get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.password });
+    let fields_list_template =
+        get_fields_as_opt_string_tokens(&fields, |mf| mf.list_template.clone());
+    let fields_show_template =
+        get_fields_as_opt_string_tokens(&fields, |mf| mf.show_template.clone());
+    let fields_form_template =
+        get_fields_as_opt_string_tokens(&fields, |mf| mf.form_template.clone());
+    let fields_tab = get_fields_as_opt_string_tokens(&fields, |mf| mf.tab.clone());
+    let fields_fieldset = get_fields_as_opt_string_tokens(&fields, |mf| mf.fieldset.clone());
+    let fields_form_row = get_fields_as_opt_string_tokens(&fields, |mf| mf.form_row.clone());
+    let fields_default_value =
+        get_fields_as_opt_string_tokens(&fields, |mf| mf.default_value.clone());
+    let fields_visible_when = get_fields_conditions(&fields, |mf| &mf.visible_when);
+    let fields_required_when = get_fields_conditions(&fields, |mf| &mf.required_when);
+    let fields_min_length = get_fields_as_opt_u32_tokens(&fields, |mf| mf.min_length);
+    let fields_max_length = get_fields_as_opt_u32_tokens(&fields, |mf| mf.max_length);
+    let fields_min = get_fields_as_opt_string_tokens(&fields, |mf| mf.min.clone());
+    let fields_max = get_fields_as_opt_string_tokens(&fields, |mf| mf.max.clone());
+    let fields_regex = get_fields_as_opt_string_tokens(&fields, |mf| mf.regex.clone());
+    let fields_one_of = get_fields_one_of(&fields);
     let fields_match_name_to_columns = get_match_name_to_column(&fields);
     let fields_list_sort_positions = get_fields_as_tokenstream(&fields, |model_field| -> usize {
         model_field.list_sort_position
@@ -333,9 +402,13 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
         });
     let fields_tenant_ref =
         get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.tenant_ref });
+    let fields_filter =
+        get_fields_as_tokenstream(&fields, |model_field| -> bool { model_field.filter });
     let fields_searchable = get_actix_admin_fields_searchable(&fields);
     let has_searchable_fields = fields_searchable.len() > 0;
     let tenant_ref_field = get_tenant_ref_field(&fields, true);
+    let fields_lookup = get_actix_admin_fields_lookup(&fields);
+    let lookup_select = get_lookup_select(&fields);
 
     let expanded = quote! {
         // Lazily-initialized static list of the entity's fields, populated on
@@ -374,6 +447,8 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                         #fields_url,
                         #fields_email,
                         #fields_wysiwyg,
+                        #fields_json,
+                        #fields_password,
                     ),
                     list_hide_column: #fields_list_hide_column,
                     list_regex_mask: list_regex_mask_regex,
@@ -385,6 +460,25 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                     shorten: shorten,
                     use_tom_select_callback: #fields_use_tom_select_callback,
                     readonly: #fields_readonly,
+                    filter: #fields_filter,
+                    json_schema: #fields_json_schema,
+                    list_template: #fields_list_template.map(|s: &str| s.to_string()),
+                    show_template: #fields_show_template.map(|s: &str| s.to_string()),
+                    form_template: #fields_form_template.map(|s: &str| s.to_string()),
+                    tab: #fields_tab.map(|s: &str| s.to_string()),
+                    fieldset: #fields_fieldset.map(|s: &str| s.to_string()),
+                    form_row: #fields_form_row.map(|s: &str| s.to_string()),
+                    default_value: #fields_default_value.map(|s: &str| s.to_string()),
+                    visible_when: #fields_visible_when,
+                    required_when: #fields_required_when,
+                    min_length: #fields_min_length,
+                    max_length: #fields_max_length,
+                    min: #fields_min.map(|s: &str| s.to_string()),
+                    max: #fields_max.map(|s: &str| s.to_string()),
+                    regex: #fields_regex.map(|s: &str| s.to_string()),
+                    compiled_regex: #fields_regex
+                        .map(|pattern: &str| parse_pattern(field_name, pattern)),
+                    one_of: #fields_one_of,
                 });
             )*
 
@@ -395,6 +489,9 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
         impl From<Model> for ActixAdminModel {
             fn from(model: Model) -> Self {
                 let display_name = model.clone().to_string();
+                #[allow(unused_mut)]
+                let mut fk_values = HashMap::new();
+                #(#fields_for_enum_labels)*
                 ActixAdminModel {
                     #field_for_primary_key,
                     values: hashmap![
@@ -402,8 +499,11 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                     ],
                     errors: HashMap::new(),
                     custom_errors: HashMap::new(),
-                    fk_values: HashMap::new(),
-                    display_name: Some(display_name)
+                    fk_values,
+                    display_name: Some(display_name),
+                    computed_values: HashMap::new(),
+                    rendered_fields: HashMap::new(),
+                    passwords_hashed: false,
                 }
             }
         }
@@ -421,50 +521,199 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
 
         #[actix_admin::prelude::async_trait]
         impl ActixAdminModelTrait for Entity {
+            async fn lookup_model(db: &DatabaseConnection, params: &ActixAdminLookupParams) -> Result<Vec<(String, String)>, ActixAdminError> {
+                let mut query = Entity::find();
+                let lookup_columns: Vec<Column> = vec![#(#fields_lookup),*];
+                if !lookup_columns.is_empty() {
+                    query = query.filter(params.search_backend.condition(
+                        &lookup_columns,
+                        Column::#primary_key_column,
+                        &params.search,
+                    ));
+                    if params.search_by_relevance {
+                        if let Some((rank, order)) = params.search_backend.relevance(
+                            &lookup_columns,
+                            Column::#primary_key_column,
+                            &params.search,
+                        ) {
+                            query = query.order_by(rank, order);
+                        }
+                    }
+                }
+
+                #tenant_ref_field
+
+                let query = query
+                    .order_by_asc(Column::#primary_key_column)
+                    .offset(params.offset)
+                    .limit(params.limit);
+                #lookup_select
+            }
+
             async fn list_model(db: &DatabaseConnection, params: &ActixAdminViewModelParams, filter_values: HashMap<String, Option<String>>) -> Result<(Option<u64>, Vec<ActixAdminModel>), ActixAdminError> {
 
                 let filter_operators: HashMap<String, Option<actix_admin::prelude::ActixAdminFilterOperator>> = params.viewmodel_filter
                     .iter()
                     .map(|f| (f.name.clone(), f.operator.clone()))
                     .collect();
+                let filter_selected: HashMap<String, Vec<String>> = params.viewmodel_filter
+                    .iter()
+                    .map(|f| (f.name.clone(), f.selected.clone()))
+                    .collect();
 
-                let sort_column = match params.sort_by.as_ref() {
-                    #(#fields_match_name_to_columns)*
-                    // Fallback: the route layer validates `sort_by` before
-                    // reaching us via `validate_sort_by`, so this arm is only
-                    // reachable if a custom caller bypassed validation. Sort
-                    // by the primary key instead of panicking.
-                    _ => Column::#primary_key_column,
-                };
-
-                let mut query = match params.sort_order {
-                    SortOrder::Asc => Entity::find().order_by_asc(sort_column),
-                    SortOrder::Desc =>  Entity::find().order_by_desc(sort_column),
-                };
+                // A backward keyset scan reverses the ordering and the page
+                // is flipped back into display order below.
+                let backward = params.cursor.as_ref().is_some_and(|c| c.is_backward());
+                let sort_columns = params.sort_columns();
 
+                let mut query = Entity::find();
                 if (#has_searchable_fields) {
-                    query = query
-                    .filter(
-                        Condition::any()
-                        #(#fields_searchable)*
-                    )
+                    let searchable_columns = [#(#fields_searchable),*];
+                    query = query.filter(params.search_backend.condition(
+                        &searchable_columns,
+                        Column::#primary_key_column,
+                        &params.search,
+                    ));
+                    // Relevance ranks ahead of the sort columns, which then
+                    // only break ties. Keyset cursors need a stable
+                    // (sort column, primary key) order, so skip it there.
+                    if params.search_by_relevance && params.cursor.is_none() {
+                        if let Some((rank, order)) = params.search_backend.relevance(
+                            &searchable_columns,
+                            Column::#primary_key_column,
+                            &params.search,
+                        ) {
+                            query = query.order_by(rank, order);
+                        }
+                    }
+                    for term in &params.search_exclude {
+                        query = query.filter(params.search_backend.exclude_condition(
+                            &searchable_columns,
+                            Column::#primary_key_column,
+                            term,
+                        ));
+                    }
+                }
+
+                let mut keyset_columns = Vec::new();
+                let mut sorted_by_primary_key = false;
+                for sort in sort_columns {
+                    let sort_column = match sort.field.as_str() {
+                        #(#fields_match_name_to_columns)*
+                        // Computed columns sort by their SQL expression,
+                        // except under keyset pagination, whose cursors need
+                        // a real column. The route layer validates every
+                        // other sort column via `validate_sort_columns`, so
+                        // an unknown one only reaches this arm if a custom
+                        // caller bypassed validation; skip it instead of
+                        // panicking.
+                        field => {
+                            if let (Some(sort_expr), None) = (params.computed_sort_exprs.get(field), &params.cursor) {
+                                query = order_by_expr(query, sort_expr, sort.order == SortOrder::Asc);
+                            }
+                            continue;
+                        }
+                    };
+                    sorted_by_primary_key |= sort.field == #primary_key_field_name;
+                    query = match (&sort.order, backward) {
+                        (SortOrder::Asc, false) | (SortOrder::Desc, true) => query.order_by_asc(sort_column),
+                        (SortOrder::Desc, false) | (SortOrder::Asc, true) => query.order_by_desc(sort_column),
+                    };
+                    keyset_columns.push((sort_column, sort.order));
+                }
+                // Stable ordering across pages: always break ties on the
+                // primary key.
+                if !sorted_by_primary_key {
+                    query = if backward {
+                        query.order_by_desc(Column::#primary_key_column)
+                    } else {
+                        query.order_by_asc(Column::#primary_key_column)
+                    };
                 }
 
                 #tenant_ref_field
 
                 let filters = Entity::get_filter();
+                let filter_names: Vec<String> = filters.iter().map(|f| f.name.clone()).collect();
                 for filter in filters {
                     let value = filter_values.get(&filter.name).unwrap_or_else(|| &None).clone();
                     let operator = filter_operators.get(&filter.name).cloned().flatten();
-                    query = filter.filter.apply(query, value, operator);
+                    let selected = filter_selected.get(&filter.name).cloned().unwrap_or_default();
+                    query = filter.filter.apply(query, value, operator, selected);
+                }
+                // Filters on plain fields, from the structured list search.
+                // Registered filters of the same name take precedence.
+                let backend = db.get_database_backend();
+                for filter in &params.viewmodel_filter {
+                    if filter_names.contains(&filter.name) {
+                        continue;
+                    }
+                    let column = match filter.name.as_str() {
+                        #(#fields_match_name_to_columns)*
+                        _ => continue,
+                    };
+                    let operator = filter.operator.clone().unwrap_or(ActixAdminFilterOperator::Equals);
+                    let condition = if filter.selected.len() > 1 {
+                        column_multi_filter_condition(column, &operator, &filter.selected, backend)?
+                    } else {
+                        column_filter_condition(column, &operator, filter.value.as_deref(), backend)?
+                    };
+                    query = query.filter(condition);
+                }
+                if let Some(group) = &params.filter_group {
+                    let column = |name: &str| -> Option<Column> {
+                        Some(match name {
+                            #(#fields_match_name_to_columns)*
+                            _ => return None,
+                        })
+                    };
+                    query = query.filter(group.condition(&column, backend)?);
                 }
 
                 let mut entities;
                 let mut model_entities = Vec::<ActixAdminModel>::new();
                 let num_pages: Option<u64>;
 
-                match (params.page, params.entities_per_page) {
-                    (Some(p), Some(e)) => {
+                match (params.page, params.entities_per_page, &params.cursor) {
+                    (_, Some(e), Some(cursor)) => {
+                        let boundary = match cursor.boundary_key() {
+                            Some(key) => match key.parse::<#primary_key_type>() {
+                                Ok(id) => {
+                                    // The boundary row must be of the
+                                    // tenant, like every listed row.
+                                    let mut query = Entity::find_by_id(id);
+                                    #tenant_ref_field
+                                    query.one(db).await?
+                                }
+                                Err(_) => return Err(ActixAdminError::bad_request(format!("Invalid cursor: {key}"))),
+                            },
+                            None => None,
+                        };
+                        // A cursor whose row has since been deleted (or is
+                        // of another tenant) restarts from the first page
+                        // rather than failing.
+                        if let Some(boundary) = boundary {
+                            let sort: Vec<_> = keyset_columns
+                                .into_iter()
+                                .map(|(column, order)| (column, order, boundary.get(column)))
+                                .collect();
+                            query = query.filter(keyset_condition(
+                                &sort,
+                                Column::#primary_key_column,
+                                boundary.get(Column::#primary_key_column),
+                                cursor,
+                                backend,
+                            ));
+                        }
+                        // One surplus row tells the caller whether another
+                        // page exists; see `ActixAdminCursor`.
+                        entities = query.limit(e + 1).all(db).await?;
+                        if backward {
+                            entities.reverse();
+                        }
+                        num_pages = None;
+                    },
+                    (Some(p), Some(e), _) => {
                         let paginator = query.paginate(db, e);
                         num_pages = Some(paginator.num_pages().await?);
 
@@ -476,7 +725,7 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                             .fetch_page(std::cmp::min(num_pages.unwrap() - 1, p.saturating_sub(1)))
                             .await?;
                     },
-                    (_, _) => {
+                    (_, _, _) => {
                         entities = query.all(db).await?;
                         num_pages = None;
                     }
@@ -493,7 +742,7 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                 Ok((num_pages, model_entities))
             }
 
-            async fn load_foreign_keys(models: &mut [ActixAdminModel], db: &DatabaseConnection) {
+            async fn load_foreign_keys<C: ConnectionTrait>(models: &mut [ActixAdminModel], db: &C) {
                 for field in Self::get_fields().iter() {
                     if field.foreign_key != "" {
                         let ids_to_select: Vec<i32> = models.iter()
@@ -513,8 +762,7 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
                         if foreign_key_values_opt.is_some() {
                             let foreign_key_values = foreign_key_values_opt.unwrap();
                             for model in models.iter_mut() {
-                                let fk_id = model.values.get(&field.field_name).unwrap();
-                                let fk_val = foreign_key_values.get(fk_id);
+                                let fk_val = model.values.get(&field.field_name).and_then(|fk_id| foreign_key_values.get(fk_id));
                                 if fk_val.is_some() {
                                     model.fk_values.insert(field.field_name.to_string(), fk_val.unwrap().to_string());
                                 }
@@ -526,8 +774,12 @@ pub fn derive_actix_admin_model(input: proc_macro::TokenStream) -> proc_macro::T
 
             fn validate_model(model: &mut ActixAdminModel) {
                 let mut errors = HashMap::<String, String>::new();
+                let hidden_fields = model.hide_fields(Self::get_fields());
                 #(#fields_for_validate_model);*
 
+                for (field_name, error) in model.required_when_errors(Self::get_fields(), &hidden_fields) {
+                    errors.entry(field_name).or_insert(error);
+                }
                 model.errors = errors;
             }
 
@@ -1,5 +1,5 @@
 use quote::ToTokens;
-use syn::Type;
+use syn::{PathArguments, Type};
 
 pub struct ModelField {
     pub ident: proc_macro2::Ident,
@@ -28,6 +28,50 @@ pub struct ModelField {
     pub list_regex_mask: String,
     pub tenant_ref: bool,
     pub use_tom_select_callback: bool,
+    pub filter: bool,
+    pub lookup: bool,
+    pub lookup_label: bool,
+    pub json: bool,
+    /// Path of a `&str` constant holding the field's JSON Schema.
+    pub json_schema: Option<String>,
+    /// Write-only secret, hashed before it is stored.
+    pub password: bool,
+    /// Name of the Tera partial rendering the list cell, falling back to
+    /// `template`.
+    pub list_template: Option<String>,
+    /// Name of the Tera partial rendering the show page value.
+    pub show_template: Option<String>,
+    /// Name of the Tera partial rendering the form widget.
+    pub form_template: Option<String>,
+    /// Tab of the create / edit form and show page the field is placed on.
+    pub tab: Option<String>,
+    /// Named section within the tab.
+    pub fieldset: Option<String>,
+    /// Fields of a fieldset sharing a `form_row` are placed side by side.
+    pub form_row: Option<String>,
+    /// Value the create form starts with.
+    pub default_value: Option<String>,
+    /// Rule of the field's visibility in the create / edit form.
+    pub visible_when: Option<FieldCondition>,
+    /// Rule under which the field cannot be left empty.
+    pub required_when: Option<FieldCondition>,
+    /// Bounds of the number of characters of a string field.
+    pub min_length: Option<u32>,
+    pub max_length: Option<u32>,
+    /// Bounds of a number, date or time field.
+    pub min: Option<String>,
+    pub max: Option<String>,
+    /// Expression the whole value of a string field has to match.
+    pub regex: Option<String>,
+    /// Allowed values of a string or integer field.
+    pub one_of: Vec<String>,
+}
+
+/// A parsed `visible_when` / `required_when` rule: holds when the value of
+/// `field` is one of `values`.
+pub struct FieldCondition {
+    pub field: String,
+    pub values: Vec<String>,
 }
 
 impl ModelField {
@@ -39,6 +83,82 @@ impl ModelField {
         matches!(&self.ty, Type::Path(type_path) if type_path.path.is_ident("String"))
     }
 
+    /// Whether the field is an enum offered by its own `select_list`, which
+    /// then implements `ActixAdminEnumSelectListTrait`.
+    pub fn is_enum_select_list(&self) -> bool {
+        !self.select_list.is_empty() && self.select_list == self.get_type_name()
+    }
+
+    /// Whether the field holds a JSON document: a `Json` / `serde_json::Value`
+    /// column, or a string column marked `json`.
+    pub fn is_json(&self) -> bool {
+        self.json
+            || self.json_schema.is_some()
+            || matches!(self.get_type_name().as_str(), "Json" | "JsonValue")
+            || (self.get_type_name() == "Value"
+                && self.get_type_path_string().contains("serde_json"))
+    }
+
+    /// Whether the field is stored as a JSON value rather than a string.
+    pub fn is_json_value(&self) -> bool {
+        self.is_json() && self.get_type_name() != "String"
+    }
+
+    /// The last segment of the (inner) type path, so that
+    /// `rust_decimal::Decimal` and `Decimal` are both `"Decimal"`. Generic
+    /// segments keep their arguments, so `DateTime<Utc>` is not mistaken
+    /// for sea-orm's naive `DateTime`.
+    pub fn get_type_name(&self) -> String {
+        let ty = if self.is_option() {
+            self.inner_type.as_ref().unwrap()
+        } else {
+            &self.ty
+        };
+
+        match ty {
+            Type::Path(type_path) => type_path
+                .path
+                .segments
+                .last()
+                .map(|segment| match segment.arguments {
+                    PathArguments::None => segment.ident.to_string(),
+                    _ => segment.to_token_stream().to_string(),
+                })
+                .unwrap_or_default(),
+            _ => panic!("not a type path"),
+        }
+    }
+
+    /// Whether the field holds plain text, the target of the length,
+    /// `regex`, `email` and `url` checks.
+    pub fn is_text(&self) -> bool {
+        self.get_type_name() == "String" && !self.is_json() && !self.is_enum_select_list()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self.get_type_name().as_str(),
+            "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize"
+        )
+    }
+
+    /// Whether the field's values are ordered, the target of `min` / `max`.
+    pub fn is_ordered(&self) -> bool {
+        self.is_integer()
+            || matches!(
+                self.get_type_name().as_str(),
+                "Decimal"
+                    | "f32"
+                    | "f64"
+                    | "Date"
+                    | "NaiveDate"
+                    | "DateTime"
+                    | "NaiveDateTime"
+                    | "Time"
+                    | "NaiveTime"
+            )
+    }
+
     pub fn get_type_path_string(&self) -> String {
         let ty = if self.is_option() {
             self.inner_type.as_ref().unwrap()
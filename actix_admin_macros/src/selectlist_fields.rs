@@ -1,9 +1,11 @@
+use crate::attributes::derive_attr::ActixAdminVariant;
 use crate::{
     model_fields::ModelField,
     struct_fields::{get_fields_for_tokenstream, get_tenant_ref_field},
 };
+use darling::FromVariant;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{DeriveInput, Ident};
 
 pub fn get_select_list_from_model(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -37,16 +39,63 @@ pub fn get_select_list_from_enum(input: proc_macro::TokenStream) -> proc_macro::
     let ast: DeriveInput = syn::parse(input).unwrap();
     let (_vis, ty, _generics) = (&ast.vis, &ast.ident, &ast.generics);
 
+    let variants = match &ast.data {
+        syn::Data::Enum(data) => &data.variants,
+        _ => panic!("DeriveActixAdminEnumSelectList can only be derived for enums"),
+    };
+    let label_arms = variants
+        .iter()
+        .filter_map(|variant| {
+            let attr = ActixAdminVariant::from_variant(variant)
+                .unwrap_or_else(|err| panic!("invalid actix_admin attribute: {err}"));
+            let label = attr.label?.value();
+            let ident = &variant.ident;
+            let fields = match variant.fields {
+                syn::Fields::Unit => quote! {},
+                syn::Fields::Unnamed(_) => quote! { (..) },
+                syn::Fields::Named(_) => quote! { { .. } },
+            };
+            Some(quote! { #ty::#ident #fields => #label.to_string(), })
+        })
+        .collect::<Vec<_>>();
+
+    // `DeriveActiveEnum` enums are submitted as the value stored in the
+    // database, other enums as their `Display` string.
+    let is_active_enum = ast.attrs.iter().any(|attr| {
+        attr.path().is_ident("sea_orm") && attr.to_token_stream().to_string().contains("rs_type")
+    });
+    let select_value = if is_active_enum {
+        quote! { <Self as sea_orm::ActiveEnum>::to_value(self).to_string() }
+    } else {
+        quote! { self.to_string().trim_start_matches("'").trim_end_matches("'").to_string() }
+    };
+
     let expanded = quote! {
+        impl ActixAdminEnumSelectListTrait for #ty {
+            fn variants() -> Vec<Self> {
+                #ty::iter().collect()
+            }
+
+            fn to_select_value(&self) -> String {
+                #select_value
+            }
+
+            fn to_select_label(&self) -> String {
+                #[allow(unreachable_patterns)]
+                match self {
+                    #(#label_arms)*
+                    _ => self.to_string().trim_start_matches("'").trim_end_matches("'").to_string(),
+                }
+            }
+        }
+
         #[async_trait]
         impl ActixAdminSelectListTrait for #ty {
             async fn get_key_value(db: &DatabaseConnection, _tenant_ref: Option<i32>) -> Result<Vec<(String, String)>, ActixAdminError> {
-                let mut fields = Vec::new();
-                for field in #ty::iter() {
-                    let field_val = field.to_string().trim_start_matches("'").trim_end_matches("'").to_string();
-                    fields.push((field_val.clone(), field_val));
-                }
-                Ok(fields)
+                Ok(#ty::variants()
+                    .iter()
+                    .map(|variant| (variant.to_select_value(), variant.to_select_label()))
+                    .collect())
             }
         }
     };
@@ -68,3 +117,31 @@ pub fn get_select_lists(fields: &Vec<ModelField>) -> Vec<proc_macro2::TokenStrea
         })
         .collect::<Vec<_>>()
 }
+
+/// Match arms loading the values offered by the auto-generated filter of
+/// each `filter` field with a `select_list` or `foreign_key`. Tom-select
+/// fields fetch their values from the search endpoint instead.
+pub fn get_filter_values(fields: &Vec<ModelField>) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .filter(|model_field| model_field.filter)
+        .filter(|model_field| !model_field.use_tom_select_callback)
+        .filter_map(|model_field| {
+            let ident_name = model_field.ident.to_string();
+            if model_field.select_list != "" {
+                let select_list_ident = Ident::new(&(model_field.select_list), Span::call_site());
+                Some(quote! {
+                    #ident_name => #select_list_ident::get_key_value(db, None).await.ok(),
+                })
+            } else {
+                let foreign_key_ident =
+                    Ident::new(model_field.foreign_key.as_ref()?, Span::call_site());
+                Some(quote! {
+                    #ident_name => #foreign_key_ident::find().all(db).await.ok().map(|models| {
+                        models.iter().map(|m| (m.id.to_string(), m.to_string())).collect()
+                    }),
+                })
+            }
+        })
+        .collect::<Vec<_>>()
+}
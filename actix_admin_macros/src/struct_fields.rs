@@ -1,8 +1,8 @@
 use crate::attributes::derive_attr;
-use crate::model_fields::ModelField;
+use crate::model_fields::{FieldCondition, ModelField};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{parse_str, DeriveInput, Fields, Ident, LitInt, LitStr, Type};
+use syn::{parse_str, DeriveInput, Fields, Ident, Lit, LitInt, LitStr, Type};
 
 pub fn get_fields_for_tokenstream(input: proc_macro::TokenStream) -> std::vec::Vec<ModelField> {
     let ast: DeriveInput = syn::parse(input).unwrap();
@@ -34,7 +34,7 @@ fn to_camelcase(s: &str) -> String {
 }
 
 pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
-    fields
+    let model_fields = fields
         .iter()
         .filter_map(|field| {
             let actix_admin_attr = derive_attr::ActixAdmin::try_from_attributes(&field.attrs)
@@ -93,7 +93,9 @@ pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
                     .clone()
                     .map_or(false, |attr| attr.readonly.is_some());
                 let is_list_hide_column = actix_admin_attr.clone().map_or(false, |attr| {
-                    attr.list_hide_column.is_some() || attr.tenant_ref.is_some()
+                    attr.list_hide_column.is_some()
+                        || attr.tenant_ref.is_some()
+                        || attr.password.is_some()
                 });
                 let is_tenant_ref = actix_admin_attr
                     .clone()
@@ -104,6 +106,103 @@ pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
                 let use_tom_select_callback = actix_admin_attr
                     .clone()
                     .map_or(false, |attr| attr.use_tom_select_callback.is_some());
+                let is_filter = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.filter.is_some());
+                let is_lookup = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.lookup.is_some());
+                let is_lookup_label = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.lookup_label.is_some());
+                let is_json = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.json.is_some());
+                let json_schema = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.json_schema)
+                    .map(|attr_field| attr_field.value());
+                let is_password = actix_admin_attr
+                    .clone()
+                    .map_or(false, |attr| attr.password.is_some());
+                let template = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.template)
+                    .map(|attr_field| attr_field.value());
+                let list_template = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.list_template)
+                    .map(|attr_field| attr_field.value())
+                    .or_else(|| template.clone());
+                let show_template = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.show_template)
+                    .map(|attr_field| attr_field.value())
+                    .or_else(|| template.clone());
+                let form_template = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.form_template)
+                    .map(|attr_field| attr_field.value())
+                    .or_else(|| template.clone());
+                let tab = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.tab)
+                    .map(|attr_field| attr_field.value());
+                let fieldset = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.fieldset)
+                    .map(|attr_field| attr_field.value());
+                let form_row = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.form_row)
+                    .map(|attr_field| attr_field.value());
+                let default_value = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.default_value)
+                    .map(|attr_field| attr_field.value());
+                let visible_when = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.visible_when)
+                    .map(|attr_field| {
+                        parse_condition(&field_ident, "visible_when", &attr_field.value())
+                    });
+                let required_when = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.required_when)
+                    .map(|attr_field| {
+                        parse_condition(&field_ident, "required_when", &attr_field.value())
+                    });
+                let min_length = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.min_length)
+                    .map(|attr_field| parse_attr_int(&field_ident, "min_length", &attr_field));
+                let max_length = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.max_length)
+                    .map(|attr_field| parse_attr_int(&field_ident, "max_length", &attr_field));
+                let min = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.min)
+                    .map(|attr_field| lit_to_string(&field_ident, "min", &attr_field));
+                let max = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.max)
+                    .map(|attr_field| lit_to_string(&field_ident, "max", &attr_field));
+                let regex = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.regex)
+                    .map(|attr_field| attr_field.value());
+                let one_of = actix_admin_attr
+                    .clone()
+                    .and_then(|attr| attr.one_of)
+                    .map_or(Vec::new(), |attr_field| {
+                        attr_field
+                            .value()
+                            .split(',')
+                            .map(|value| value.trim().to_string())
+                            .filter(|value| !value.is_empty())
+                            .collect()
+                    });
                 let list_regex_mask = actix_admin_attr.clone().map_or("".to_string(), |attr| {
                     attr.list_regex_mask.map_or("".to_string(), |attr_field| {
                         (LitStr::from(attr_field)).value()
@@ -161,13 +260,189 @@ pub fn filter_fields(fields: &Fields) -> Vec<ModelField> {
                     dateformat: dateformat,
                     shorten: shorten,
                     use_tom_select_callback: use_tom_select_callback,
+                    filter: is_filter,
+                    lookup: is_lookup,
+                    lookup_label: is_lookup_label,
+                    json: is_json,
+                    json_schema: json_schema,
+                    password: is_password,
+                    list_template: list_template,
+                    show_template: show_template,
+                    form_template: form_template,
+                    tab: tab,
+                    fieldset: fieldset,
+                    form_row: form_row,
+                    default_value: default_value,
+                    visible_when: visible_when,
+                    required_when: required_when,
+                    min_length: min_length,
+                    max_length: max_length,
+                    min: min,
+                    max: max,
+                    regex: regex,
+                    one_of: one_of,
                 };
+                check_constraints(&model_field);
+                // A hidden field is saved without a value.
+                if model_field.visible_when.is_some() && !model_field.is_option() {
+                    panic!(
+                        "visible_when of `{}` requires an Option field",
+                        model_field.ident
+                    );
+                }
                 Some(model_field)
             } else {
                 None
             }
         })
-        .collect::<Vec<_>>()
+        .collect::<Vec<_>>();
+    check_conditions(&model_fields);
+    model_fields
+}
+
+/// Parse a `visible_when` / `required_when` rule of the form `field = value`
+/// or `field in (value, ...)`. Values may be quoted.
+fn parse_condition(ident: &Ident, attr_name: &str, rule: &str) -> FieldCondition {
+    let invalid = || -> ! {
+        panic!(
+            "invalid {attr_name} `{rule}` of `{ident}`, expected `field = value` or `field in (value, ...)`"
+        )
+    };
+    let unquote = |value: &str| {
+        let value = value.trim();
+        value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+            .unwrap_or(value)
+            .to_string()
+    };
+
+    // ` in ` first, as a value of the list may contain `=`.
+    let (field, values) = if let Some((field, list)) = rule.split_once(" in ") {
+        let list = list.trim();
+        let list = list
+            .strip_prefix('(')
+            .and_then(|l| l.strip_suffix(')'))
+            .unwrap_or_else(|| invalid());
+        (field, list.split(',').map(unquote).collect())
+    } else if let Some((field, value)) = rule.split_once('=') {
+        (field, vec![unquote(value)])
+    } else {
+        invalid()
+    };
+
+    let field = field.trim();
+    if field.is_empty() || field.contains(char::is_whitespace) {
+        invalid();
+    }
+    FieldCondition {
+        field: field.to_string(),
+        values,
+    }
+}
+
+/// Reject rules referring to a field the struct doesn't have, which would
+/// never hold.
+fn check_conditions(model_fields: &[ModelField]) {
+    for model_field in model_fields {
+        let rules = [
+            ("visible_when", &model_field.visible_when),
+            ("required_when", &model_field.required_when),
+        ];
+        for (attr_name, condition) in rules {
+            if let Some(condition) = condition {
+                if !model_fields
+                    .iter()
+                    .any(|f| f.ident == condition.field.as_str())
+                {
+                    panic!(
+                        "{attr_name} of `{}` refers to unknown field `{}`",
+                        model_field.ident, condition.field
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn parse_attr_int(ident: &Ident, attr_name: &str, lit: &LitInt) -> u32 {
+    lit.base10_parse()
+        .unwrap_or_else(|_| panic!("{attr_name} of `{ident}` must be a non-negative integer"))
+}
+
+/// The value of a `min` / `max` attribute, given as a number or a string.
+fn lit_to_string(ident: &Ident, attr_name: &str, lit: &Lit) -> String {
+    match lit {
+        Lit::Str(lit) => lit.value(),
+        Lit::Int(lit) => lit.base10_digits().to_string(),
+        Lit::Float(lit) => lit.base10_digits().to_string(),
+        _ => panic!("{attr_name} of `{ident}` must be a number or a string"),
+    }
+}
+
+/// Whether a `min` / `max` bound parses into the field's type, like
+/// `parse_bound` does when the model is validated. `None` if the bounds of
+/// the type can't be checked.
+fn is_valid_bound(type_name: &str, bound: &str) -> Option<bool> {
+    let valid = match type_name {
+        "i8" => bound.parse::<i8>().is_ok(),
+        "i16" => bound.parse::<i16>().is_ok(),
+        "i32" => bound.parse::<i32>().is_ok(),
+        "i64" => bound.parse::<i64>().is_ok(),
+        "isize" => bound.parse::<isize>().is_ok(),
+        "u8" => bound.parse::<u8>().is_ok(),
+        "u16" => bound.parse::<u16>().is_ok(),
+        "u32" => bound.parse::<u32>().is_ok(),
+        "u64" => bound.parse::<u64>().is_ok(),
+        "usize" => bound.parse::<usize>().is_ok(),
+        "f32" => bound.parse::<f32>().is_ok(),
+        "f64" => bound.parse::<f64>().is_ok(),
+        "Decimal" => bound.parse::<rust_decimal::Decimal>().is_ok(),
+        "Date" | "NaiveDate" => bound.parse::<chrono::NaiveDate>().is_ok(),
+        "DateTime" | "NaiveDateTime" => bound.parse::<chrono::NaiveDateTime>().is_ok(),
+        "Time" | "NaiveTime" => bound.parse::<chrono::NaiveTime>().is_ok(),
+        _ => return None,
+    };
+    Some(valid)
+}
+
+/// Reject validation attributes on fields of a type they don't apply to.
+fn check_constraints(model_field: &ModelField) {
+    let ident = &model_field.ident;
+    if (model_field.min_length.is_some()
+        || model_field.max_length.is_some()
+        || model_field.regex.is_some())
+        && !model_field.is_text()
+    {
+        panic!("min_length, max_length and regex of `{ident}` require a String field");
+    }
+    if (model_field.min.is_some() || model_field.max.is_some()) && !model_field.is_ordered() {
+        panic!("min and max of `{ident}` require a number, date or time field");
+    }
+    for (attr_name, bound) in [("min", &model_field.min), ("max", &model_field.max)] {
+        if let Some(bound) = bound {
+            let type_name = model_field.get_type_name();
+            match is_valid_bound(&type_name, bound) {
+                Some(true) => {}
+                Some(false) => {
+                    panic!("{attr_name} `{bound}` of `{ident}` is not a valid {type_name}")
+                }
+                None => panic!("min and max of `{ident}` are not supported on {type_name}"),
+            }
+        }
+    }
+    if let Some(regex) = &model_field.regex {
+        // Compiled like `parse_pattern` does at runtime.
+        if let Err(err) = regex::Regex::new(&format!("^(?:{regex})$")) {
+            panic!("invalid regex of `{ident}`: {err}");
+        }
+    }
+    if !model_field.one_of.is_empty()
+        && (model_field.password || !(model_field.is_text() || model_field.is_integer()))
+    {
+        panic!("one_of of `{ident}` requires a String or integer field");
+    }
 }
 
 fn extract_type_from_option(ty: &syn::Type) -> Option<syn::Type> {
@@ -262,6 +537,54 @@ pub fn get_fields_as_opt_u16_tokens(
         .collect()
 }
 
+/// Same as [`get_fields_as_opt_string_tokens`] but for `Option<u32>`.
+pub fn get_fields_as_opt_u32_tokens(
+    fields: &Vec<ModelField>,
+    accessor: fn(&ModelField) -> Option<u32>,
+) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|f| !f.primary_key && !f.tenant_ref)
+        .map(|f| match accessor(f) {
+            Some(n) => quote! { Some(#n) },
+            None => quote! { None },
+        })
+        .collect()
+}
+
+/// The `Option<ActixAdminFieldCondition>` of each field's `visible_when` /
+/// `required_when` rule.
+pub fn get_fields_conditions(
+    fields: &Vec<ModelField>,
+    accessor: fn(&ModelField) -> &Option<FieldCondition>,
+) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|f| !f.primary_key && !f.tenant_ref)
+        .map(|f| match accessor(f) {
+            Some(FieldCondition { field, values }) => quote! {
+                Some(ActixAdminFieldCondition {
+                    field: #field.to_string(),
+                    values: vec![#(#values.to_string()),*],
+                })
+            },
+            None => quote! { None },
+        })
+        .collect()
+}
+
+/// The `one_of` values of each field as a `Vec<String>` expression.
+pub fn get_fields_one_of(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|f| !f.primary_key && !f.tenant_ref)
+        .map(|f| {
+            let one_of = &f.one_of;
+            quote! { vec![#(#one_of.to_string()),*] }
+        })
+        .collect()
+}
+
 pub fn get_fields_as_tokenstream<T: ToTokens>(
     fields: &Vec<ModelField>,
     accessor: fn(&ModelField) -> T,
@@ -280,9 +603,31 @@ pub fn get_fields_as_tokenstream<T: ToTokens>(
         .collect::<Vec<_>>()
 }
 
+/// The parsed `json_schema` of each field, read from the constant the
+/// attribute names.
+pub fn get_fields_json_schema(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|f| !f.primary_key && !f.tenant_ref)
+        .map(|f| match &f.json_schema {
+            Some(schema) => {
+                let field_name = f.ident.to_string();
+                let schema_path: syn::Path = parse_str(schema).unwrap_or_else(|_| {
+                    panic!("json_schema of `{field_name}` must name a constant")
+                });
+                quote! { Some(parse_json_schema(#field_name, #schema_path)) }
+            }
+            None => quote! { None },
+        })
+        .collect()
+}
+
+/// Match arms from field names to the columns `list` may sort and filter
+/// on. Password hashes are left out, so that they cannot be probed.
 pub fn get_match_name_to_column(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     fields
         .iter()
+        .filter(|model_field| !model_field.password)
         .map(|model_field| {
             let column_name = model_field.ident.to_string();
             let column_name_capitalized = to_camelcase(&column_name);
@@ -302,12 +647,92 @@ pub fn get_actix_admin_fields_searchable(fields: &Vec<ModelField>) -> Vec<TokenS
             let column_name = capitalize_first_letter(&model_field.ident.to_string());
             let column_ident = Ident::new(&column_name, Span::call_site());
             quote! {
-                .add(Column::#column_ident.contains(&params.search))
+                Column::#column_ident
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Columns matched by the tom-select lookup: the `lookup` fields, or the
+/// `searchable` ones when none is marked.
+pub fn get_actix_admin_fields_lookup(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    let has_lookup_fields = fields.iter().any(|model_field| model_field.lookup);
+    fields
+        .iter()
+        .filter(|model_field| {
+            if has_lookup_fields {
+                model_field.lookup
+            } else {
+                model_field.searchable
+            }
+        })
+        .map(|model_field| {
+            let column_name = capitalize_first_letter(&model_field.ident.to_string());
+            let column_ident = Ident::new(&column_name, Span::call_site());
+            quote! {
+                Column::#column_ident
             }
         })
         .collect::<Vec<_>>()
 }
 
+/// Loads the tom-select lookup page from `query`: only the primary key and
+/// the `lookup_label` column, or whole models labelled by their `Display`
+/// impl when no field is marked.
+pub fn get_lookup_select(fields: &Vec<ModelField>) -> TokenStream {
+    let primary_key_field = fields
+        .iter()
+        .find(|model_field| model_field.primary_key)
+        .expect("model has no primary key field");
+    let primary_key_ident = &primary_key_field.ident;
+    let primary_key_column = Ident::new(
+        &capitalize_first_letter(&primary_key_ident.to_string()),
+        Span::call_site(),
+    );
+    let primary_key_ty = &primary_key_field.ty;
+    let label_fields: Vec<&ModelField> = fields
+        .iter()
+        .filter(|model_field| model_field.lookup_label)
+        .collect();
+
+    match label_fields.len() {
+        0 => quote! {
+            let models = query.all(db).await?;
+            Ok(models
+                .into_iter()
+                .map(|model| (model.#primary_key_ident.to_string(), model.to_string()))
+                .collect())
+        },
+        1 => {
+            let label_field = label_fields[0];
+            let label_column = Ident::new(
+                &capitalize_first_letter(&label_field.ident.to_string()),
+                Span::call_site(),
+            );
+            let label_ty = &label_field.ty;
+            let label_to_string = if label_field.is_option() {
+                quote! { label.map(|label| label.to_string()).unwrap_or_default() }
+            } else {
+                quote! { label.to_string() }
+            };
+            quote! {
+                let rows: Vec<(#primary_key_ty, #label_ty)> = query
+                    .select_only()
+                    .column(Column::#primary_key_column)
+                    .column(Column::#label_column)
+                    .into_tuple()
+                    .all(db)
+                    .await?;
+                Ok(rows
+                    .into_iter()
+                    .map(|(id, label)| (id.to_string(), #label_to_string))
+                    .collect())
+            }
+        }
+        _ => panic!("Model has multiple lookup_label fields, but only one is allowed"),
+    }
+}
+
 pub fn get_set_tenant_ref_field(fields: &Vec<ModelField>) -> TokenStream {
     let tenant_ref_fields: Vec<&ModelField> = fields
         .iter()
@@ -467,6 +892,24 @@ pub fn get_fields_for_from_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
             let ident_name = model_field.ident.to_string();
             let ident = model_field.ident.to_owned();
 
+            // Stored passwords are never rendered back.
+            if model_field.password {
+                return quote! {
+                    #ident_name => String::new()
+                };
+            }
+
+            if model_field.is_enum_select_list() {
+                return match model_field.is_option() {
+                    true => quote! {
+                        #ident_name => model.#ident.as_ref().map(ActixAdminEnumSelectListTrait::to_select_value).unwrap_or_default()
+                    },
+                    false => quote! {
+                        #ident_name => ActixAdminEnumSelectListTrait::to_select_value(&model.#ident)
+                    },
+                };
+            }
+
             match model_field.is_option() {
                 true => {
                     quote! {
@@ -486,32 +929,183 @@ pub fn get_fields_for_from_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
         .collect::<Vec<_>>()
 }
 
-pub fn get_fields_for_validate_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
-    fields.iter()
-        .filter(|model_field| !model_field.primary_key && !model_field.tenant_ref)
+/// Statements adding the label of each enum select list to `fk_values`, so
+/// that list, show and export display it instead of the stored value.
+pub fn get_fields_for_enum_labels(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|model_field| !model_field.primary_key && model_field.is_enum_select_list())
         .map(|model_field| {
             let ident_name = model_field.ident.to_string();
-            let ty = model_field.ty.to_owned();
-            let type_path = model_field.get_type_path_string();
-            let is_option_or_string = model_field.is_option() || model_field.is_string();
-            let is_allowed_to_be_empty = !model_field.not_empty;
-
-            let res = match (model_field.is_option(), type_path.as_str()) {
-                (_, "DateTime") => quote! { model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok(); },
-                (_, "Date") => quote! { model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok(); },
-                (_, "bool") => quote! { model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok(); },
-                (true, _) => {
-                    let inner_ty = model_field.inner_type.to_owned().unwrap();
-                    quote! { model.get_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok(); }
+            let ident = model_field.ident.to_owned();
+            match model_field.is_option() {
+                true => quote! {
+                    if let Some(value) = &model.#ident {
+                        fk_values.insert(#ident_name.to_string(), ActixAdminEnumSelectListTrait::to_select_label(value));
+                    }
                 },
-                (false, _) => quote! { model.get_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)).ok(); }
-            };
+                false => quote! {
+                    fk_values.insert(#ident_name.to_string(), ActixAdminEnumSelectListTrait::to_select_label(&model.#ident));
+                },
+            }
+        })
+        .collect::<Vec<_>>()
+}
 
-            res
+pub fn get_fields_for_validate_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .filter(|model_field| !model_field.primary_key && !model_field.tenant_ref)
+        .map(|model_field| {
+            let validation = get_field_validation(model_field);
+            if model_field.visible_when.is_none() {
+                return validation;
+            }
+            let ident_name = model_field.ident.to_string();
+            quote! {
+                if !hidden_fields.iter().any(|field| field == #ident_name) {
+                    #validation
+                }
+            }
         })
         .collect()
 }
 
+fn get_field_validation(model_field: &ModelField) -> TokenStream {
+    let ident_name = model_field.ident.to_string();
+    let ty = model_field.ty.to_owned();
+    let type_name = model_field.get_type_name();
+    let is_option_or_string = model_field.is_option() || model_field.is_string();
+    let is_allowed_to_be_empty = !model_field.not_empty;
+
+    if model_field.is_json() {
+        return quote! {
+            if let Ok(Some(value)) = model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).map_err(|err| errors.insert(#ident_name.to_string(), err)) {
+                let schema = Self::get_fields().iter().find(|field| field.field_name == #ident_name).and_then(|field| field.json_schema.as_ref());
+                if let Some(schema) = schema {
+                    errors.extend(json_schema_errors(#ident_name, schema, &value));
+                }
+            }
+        };
+    }
+
+    let value = if model_field.password {
+        let is_option = model_field.is_option();
+        quote! { model.get_password(#ident_name, #is_option) }
+    } else {
+        match (model_field.is_option(), type_name.as_str()) {
+            (true, _) if model_field.is_enum_select_list() => {
+                let inner_ty = model_field.inner_type.to_owned().unwrap();
+                quote! { model.get_select_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (false, _) if model_field.is_enum_select_list() => {
+                quote! { model.get_select_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (_, "DateTime" | "NaiveDateTime") => {
+                quote! { model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (_, "Date" | "NaiveDate") => {
+                quote! { model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (_, "Time" | "NaiveTime") => {
+                quote! { model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (_, "bool") => {
+                quote! { model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (true, "Decimal" | "f32" | "f64") => {
+                let inner_ty = model_field.inner_type.to_owned().unwrap();
+                quote! { model.get_number::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (false, "Decimal" | "f32" | "f64") => {
+                quote! { model.get_number::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (true, _) => {
+                let inner_ty = model_field.inner_type.to_owned().unwrap();
+                quote! { model.get_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+            (false, _) => {
+                quote! { model.get_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty) }
+            }
+        }
+    };
+
+    let checks = get_constraint_checks(model_field);
+    if checks.is_empty() {
+        return quote! {
+            #value.map_err(|err| errors.insert(#ident_name.to_string(), err)).ok();
+        };
+    }
+    // An empty string is left to `not_empty`, and a password is checked
+    // before it is replaced by its hash.
+    let is_set = if model_field.password {
+        quote! { !value.is_empty() && !model.passwords_hashed }
+    } else if model_field.is_text() {
+        quote! { !value.is_empty() }
+    } else {
+        quote! { true }
+    };
+    quote! {
+        if let Ok(Some(value)) = #value.map_err(|err| errors.insert(#ident_name.to_string(), err)) {
+            if #is_set {
+                let error: Option<String> = None #(.or_else(|| #checks))*;
+                if let Some(error) = error {
+                    errors.insert(#ident_name.to_string(), error);
+                }
+            }
+        }
+    }
+}
+
+/// The checks of the field's validation attributes on its parsed `value`,
+/// each an `Option<String>` of the error.
+fn get_constraint_checks(model_field: &ModelField) -> Vec<TokenStream> {
+    let ident_name = model_field.ident.to_string();
+    let mut checks = Vec::new();
+
+    if model_field.min_length.is_some() || model_field.max_length.is_some() {
+        let min_length = opt_tokens(model_field.min_length);
+        let max_length = opt_tokens(model_field.max_length);
+        checks.push(quote! { length_error(&value, #min_length, #max_length) });
+    }
+    if model_field.min.is_some() || model_field.max.is_some() {
+        let bound = |bound: &Option<String>| match bound {
+            Some(bound) => quote! { Some(parse_bound(#ident_name, #bound)) },
+            None => quote! { None },
+        };
+        let min = bound(&model_field.min);
+        let max = bound(&model_field.max);
+        checks.push(quote! { range_error(&value, #min, #max) });
+    }
+    if model_field.regex.is_some() {
+        checks.push(quote! {
+            Self::get_fields()
+                .iter()
+                .find(|field| field.field_name == #ident_name)
+                .and_then(|field| field.compiled_regex.as_ref())
+                .and_then(|regex| pattern_error(&value, regex))
+        });
+    }
+    if model_field.email && model_field.is_text() {
+        checks.push(quote! { email_error(&value) });
+    }
+    if model_field.url && model_field.is_text() {
+        checks.push(quote! { url_error(&value) });
+    }
+    if !model_field.one_of.is_empty() {
+        let one_of = &model_field.one_of;
+        checks.push(quote! { one_of_error(&value.to_string(), &[#(#one_of),*]) });
+    }
+    checks
+}
+
+fn opt_tokens<T: ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
 pub fn get_fields_for_create_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
     fields
         .iter()
@@ -522,29 +1116,60 @@ pub fn get_fields_for_create_model(fields: &Vec<ModelField>) -> Vec<TokenStream>
             let ident_name = model_field.ident.to_string();
             let ident = model_field.ident.to_owned();
             let ty = model_field.ty.to_owned();
-            let type_path = model_field.get_type_path_string();
+            let type_name = model_field.get_type_name();
 
             let is_option_or_string = model_field.is_option() || model_field.is_string();
             let is_allowed_to_be_empty = !model_field.not_empty;
 
-            let res = match (model_field.is_option(), model_field.is_string(), type_path.as_str()) {
+            let res = match (model_field.is_option(), model_field.is_string(), type_name.as_str()) {
+                (true, _, _) if model_field.password => {
+                    quote! {
+                        #ident: Set(model.get_password(#ident_name, true).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.password => {
+                    quote! {
+                        #ident: Set(model.get_password(#ident_name, false).unwrap().unwrap_or_default())
+                    }
+                },
+                (true, _, _) if model_field.is_json_value() => {
+                    quote! {
+                        #ident: Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (true, _, _) if model_field.is_enum_select_list() => {
+                    let inner_ty = model_field.inner_type.to_owned().unwrap();
+                    quote! {
+                        #ident: Set(model.get_select_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_enum_select_list() => {
+                    quote! {
+                        #ident: Set(model.get_select_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_json_value() => {
+                    quote! {
+                        #ident: Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
                 // is DateTime
-                (true , _, "DateTime") => {
+                (true , _, "DateTime" | "NaiveDateTime") => {
                     quote! {
                         #ident: Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
-                (false , _, "DateTime") => {
+                (false , _, "DateTime" | "NaiveDateTime") => {
                     quote! {
                         #ident: Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
-                (true , _, "Date") => {
+                (true , _, "Date" | "NaiveDate") => {
                     quote! {
                         #ident: Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
-                (false , _, "Date") => {
+                (false , _, "Date" | "NaiveDate") => {
                     quote! {
                         #ident: Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
@@ -554,6 +1179,27 @@ pub fn get_fields_for_create_model(fields: &Vec<ModelField>) -> Vec<TokenStream>
                         #ident: Set(model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
+                (true , _, "Time" | "NaiveTime") => {
+                    quote! {
+                        #ident: Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false , _, "Time" | "NaiveTime") => {
+                    quote! {
+                        #ident: Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (true, _, "Decimal" | "f32" | "f64") => {
+                    let inner_ty = model_field.inner_type.to_owned().unwrap();
+                    quote! {
+                        #ident: Set(model.get_number::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false, _, "Decimal" | "f32" | "f64") => {
+                    quote! {
+                        #ident: Set(model.get_number::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
                 // Default fields
                 (true, _, _) => {
                     let inner_ty = model_field.inner_type.to_owned().unwrap();
@@ -590,37 +1236,94 @@ pub fn get_fields_for_edit_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
             let ident_name = model_field.ident.to_string();
             let ident = model_field.ident.to_owned();
             let ty = model_field.ty.to_owned();
-            let type_path = model_field.get_type_path_string();
+            let type_name = model_field.get_type_name();
 
             let is_option_or_string = model_field.is_option() || model_field.is_string();
             let is_allowed_to_be_empty = !model_field.not_empty;
 
-            let res = match (model_field.is_option(), model_field.is_string(), type_path.as_str()) {
+            let res = match (model_field.is_option(), model_field.is_string(), type_name.as_str()) {
+                // An empty password keeps the stored one.
+                (true, _, _) if model_field.password => {
+                    quote! {
+                        if let Some(password) = model.get_password(#ident_name, true).unwrap() {
+                            entity.#ident = Set(Some(password))
+                        }
+                    }
+                },
+                (false, _, _) if model_field.password => {
+                    quote! {
+                        if let Some(password) = model.get_password(#ident_name, false).unwrap() {
+                            entity.#ident = Set(password)
+                        }
+                    }
+                },
+                (true, _, _) if model_field.is_json_value() => {
+                    quote! {
+                        entity.#ident = Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (true, _, _) if model_field.is_enum_select_list() => {
+                    let inner_ty = model_field.inner_type.to_owned().unwrap();
+                    quote! {
+                        entity.#ident = Set(model.get_select_value::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_enum_select_list() => {
+                    quote! {
+                        entity.#ident = Set(model.get_select_value::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (false, _, _) if model_field.is_json_value() => {
+                    quote! {
+                        entity.#ident = Set(model.get_json(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
                 (_, _, "bool") => {
                     quote! {
                         entity.#ident = Set(model.get_bool(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
-                (true , _, "DateTime") => {
+                (true , _, "DateTime" | "NaiveDateTime") => {
                     quote! {
                         entity.#ident = Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
-                (false , _, "DateTime") => {
+                (false , _, "DateTime" | "NaiveDateTime") => {
                     quote! {
                         entity.#ident = Set(model.get_datetime(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
-                (true , _, "Date") => {
+                (true , _, "Date" | "NaiveDate") => {
                     quote! {
                         entity.#ident = Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
                     }
                 },
-                (false , _, "Date") => {
+                (false , _, "Date" | "NaiveDate") => {
                     quote! {
                         entity.#ident = Set(model.get_date(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
                     }
                 },
+                (true , _, "Time" | "NaiveTime") => {
+                    quote! {
+                        entity.#ident = Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false , _, "Time" | "NaiveTime") => {
+                    quote! {
+                        entity.#ident = Set(model.get_time(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
+                (true, _, "Decimal" | "f32" | "f64") => {
+                    let inner_ty = model_field.inner_type.to_owned().unwrap();
+                    quote! {
+                        entity.#ident = Set(model.get_number::<#inner_ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap())
+                    }
+                },
+                (false, _, "Decimal" | "f32" | "f64") => {
+                    quote! {
+                        entity.#ident = Set(model.get_number::<#ty>(#ident_name, #is_option_or_string, #is_allowed_to_be_empty).unwrap().unwrap())
+                    }
+                },
                 (true, _, _) => {
                     let inner_ty = model_field.inner_type.to_owned().unwrap();
                     quote! {
@@ -643,3 +1346,127 @@ pub fn get_fields_for_edit_model(fields: &Vec<ModelField>) -> Vec<TokenStream> {
         })
         .collect::<Vec<_>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields_of(item: &str) -> Vec<ModelField> {
+        let ast: DeriveInput = parse_str(item).unwrap();
+        match ast.data {
+            syn::Data::Struct(ref s) => filter_fields(&s.fields),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn conditions_are_parsed() {
+        let ident = Ident::new("note", Span::call_site());
+        let condition = parse_condition(&ident, "visible_when", r#"status in ("a=b", c)"#);
+        assert_eq!(condition.field, "status");
+        assert_eq!(condition.values, vec!["a=b".to_string(), "c".to_string()]);
+
+        let condition = parse_condition(&ident, "required_when", "status = 'two words'");
+        assert_eq!(condition.values, vec!["two words".to_string()]);
+    }
+
+    #[test]
+    #[should_panic(expected = "min `ten` of `count` is not a valid i32")]
+    fn invalid_integer_bounds_panic() {
+        fields_of(
+            r#"struct Model {
+                #[actix_admin(min = "ten")]
+                count: i32,
+            }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "max `1.5` of `count` is not a valid u8")]
+    fn fractional_integer_bounds_panic() {
+        fields_of(
+            r#"struct Model {
+                #[actix_admin(max = 1.5)]
+                count: Option<u8>,
+            }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "min `2024-13-01` of `published` is not a valid NaiveDate")]
+    fn invalid_date_bounds_panic() {
+        fields_of(
+            r#"struct Model {
+                #[actix_admin(min = "2024-13-01")]
+                published: NaiveDate,
+            }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "max `12.5.0` of `price` is not a valid Decimal")]
+    fn invalid_decimal_bounds_panic() {
+        fields_of(
+            r#"struct Model {
+                #[actix_admin(max = "12.5.0")]
+                price: Option<Decimal>,
+            }"#,
+        );
+    }
+
+    #[test]
+    fn valid_bounds_are_accepted() {
+        let fields = fields_of(
+            r#"struct Model {
+                #[actix_admin(min = -5, max = "10")]
+                count: i32,
+                #[actix_admin(min = 0.5, max = 2)]
+                ratio: f64,
+                #[actix_admin(min = "0.01", max = "99999999999999999.99")]
+                price: Decimal,
+                #[actix_admin(min = "2020-01-01")]
+                published: Date,
+                #[actix_admin(min = "2020-01-01T08:00:00")]
+                posted_at: DateTime,
+                #[actix_admin(max = "18:30:00")]
+                closes: NaiveTime,
+            }"#,
+        );
+        assert_eq!(fields[1].min.as_deref(), Some("0.5"));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid regex of `code`")]
+    fn invalid_regex_panics() {
+        fields_of(
+            r#"struct Model {
+                #[actix_admin(regex = "[A-Z")]
+                code: String,
+            }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid visible_when `status` of `note`")]
+    fn malformed_conditions_panic() {
+        fields_of(
+            r#"struct Model {
+                status: String,
+                #[actix_admin(visible_when = "status")]
+                note: Option<String>,
+            }"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "visible_when of `note` refers to unknown field `stauts`")]
+    fn conditions_on_unknown_fields_panic() {
+        fields_of(
+            r#"struct Model {
+                status: String,
+                #[actix_admin(visible_when = "stauts = cancelled")]
+                note: Option<String>,
+            }"#,
+        );
+    }
+}
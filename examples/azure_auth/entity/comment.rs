@@ -65,6 +65,10 @@ impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
     }
 }
 
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
 impl ActixAdminModelFilterTrait<Entity> for Entity {}
 
 impl Display for Model {
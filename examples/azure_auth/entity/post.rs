@@ -90,4 +90,8 @@ impl FromStr for Tea {
 
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
 impl ActixAdminModelFilterTrait<Entity> for Entity {}
@@ -74,6 +74,10 @@ impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
     }
 }
 
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
 impl Display for Model {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match &*self {
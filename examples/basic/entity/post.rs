@@ -126,6 +126,10 @@ impl FromStr for Tea {
 
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
 impl ActixAdminModelFilterTrait<Entity> for Entity {}
 
 // Custom bulk action registered via `add_bulk_action_for_entity` in main.rs.
@@ -137,7 +141,7 @@ impl ActixAdminModelFilterTrait<Entity> for Entity {}
 impl actix_admin::routes::ActixAdminBulkActionDispatch for Entity {
     async fn run_bulk_action(
         name: &str,
-        _db: &sea_orm::DatabaseConnection,
+        _db: &sea_orm::DatabaseTransaction,
         ids: Vec<Self::Id>,
         _tenant_ref: Option<i32>,
     ) -> Result<Option<String>, ActixAdminError> {
@@ -31,6 +31,10 @@ pub enum Relation {}
 
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
 impl ActixAdminModelFilterTrait<Entity> for Entity {}
 
 impl Display for Model {
@@ -73,6 +73,7 @@ fn create_actix_admin_builder() -> ActixAdminBuilder {
         custom_css_paths: None,
         custom_js_paths: None,
         enable_csrf: false,
+        hash_password: hash_password_argon2,
     };
 
     let mut admin_builder = ActixAdminBuilder::new(configuration);
@@ -1,11 +1,11 @@
 use crate::routes::{
-    create_get, create_post, delete, delete_many, download, edit_get, edit_post, index, list,
-    not_found, show,
+    create_get, create_post, delete, delete_many, download, duplicate, duplicate_post, edit_get,
+    edit_post, index, list, not_found, show, validate, validate_edit,
 };
 use crate::{
     prelude::*,
     routes::{
-        bulk_action, delete_file, display_card_grid, export_csv, search,
+        bulk_action, delete_file, display_card_grid, export_csv, global_search, search,
         ActixAdminBulkActionDispatch,
     },
     ActixAdminMenuElement,
@@ -56,6 +56,7 @@ impl ActixAdminBuilder {
             actix_admin: ActixAdmin {
                 entity_names: BTreeMap::new(),
                 view_models: HashMap::new(),
+                entity_lists: HashMap::new(),
                 card_grids: HashMap::new(),
                 configuration,
                 tera: crate::tera_templates::get_tera(),
@@ -89,6 +90,10 @@ impl ActixAdminBuilder {
                 .route("/create", web::post().to(create_post::<E>))
                 .route("/edit/{id}", web::get().to(edit_get::<E>))
                 .route("/edit/{id}", web::post().to(edit_post::<E>))
+                .route("/duplicate/{id}", web::get().to(duplicate::<E>))
+                .route("/duplicate/{id}", web::post().to(duplicate_post::<E>))
+                .route("/validate", web::post().to(validate::<E>))
+                .route("/validate/{id}", web::post().to(validate_edit::<E>))
                 .route("/delete", web::delete().to(delete_many::<E>))
                 .route("/delete/{id}", web::delete().to(delete::<E>))
                 .route("/show/{id}", web::get().to(show::<E>))
@@ -125,6 +130,9 @@ impl ActixAdminBuilder {
         self.actix_admin
             .view_models
             .insert(E::get_entity_name(), view_model.clone());
+        self.actix_admin
+            .entity_lists
+            .insert(E::get_entity_name(), list_entities::<E>);
     }
 
     pub fn add_custom_handler_for_index(&mut self, route: Route) {
@@ -166,6 +174,52 @@ impl ActixAdminBuilder {
         }
     }
 
+    /// Add a computed (virtual) column to an entity's list, show page and
+    /// CSV export, see [`ActixAdminComputedColumn`].
+    pub fn add_computed_column_for_entity<E: ActixAdminViewModelTrait + 'static>(
+        &mut self,
+        column: ActixAdminComputedColumn,
+    ) {
+        let entity_name = E::get_entity_name();
+        self.actix_admin
+            .view_models
+            .get_mut(&entity_name)
+            .unwrap_or_else(|| panic!("add_computed_column_for_entity: entity `{entity_name}` must be registered via add_entity first"))
+            .computed_columns
+            .push(column);
+    }
+
+    /// Compute the value a field of the entity's create form starts with per
+    /// request, e.g. from the session, see [`ActixAdminFieldDefaultFn`].
+    pub fn add_field_default_for_entity<E: ActixAdminViewModelTrait + 'static>(
+        &mut self,
+        field_name: &str,
+        default: ActixAdminFieldDefaultFn,
+    ) {
+        let entity_name = E::get_entity_name();
+        let vm = self
+            .actix_admin
+            .view_models
+            .get_mut(&entity_name)
+            .unwrap_or_else(|| panic!("add_field_default_for_entity: entity `{entity_name}` must be registered via add_entity first"));
+        if !vm.fields.iter().any(|field| field.field_name == field_name) {
+            panic!(
+                "add_field_default_for_entity: entity `{entity_name}` has no field `{field_name}`"
+            );
+        }
+        vm.field_defaults.insert(field_name.to_string(), default);
+    }
+
+    /// Register a Tera partial that fields can name in their `template`,
+    /// `list_template`, `show_template` or `form_template` attribute.
+    /// Panics if `content` is not a valid template.
+    pub fn add_field_template(&mut self, name: &str, content: &str) {
+        self.actix_admin
+            .tera
+            .add_raw_template(name, content)
+            .unwrap_or_else(|err| panic!("add_field_template: invalid template `{name}`: {err}"));
+    }
+
     pub fn add_custom_handler_to_category(
         &mut self,
         menu_element_name: &str,
@@ -285,6 +339,7 @@ impl ActixAdminBuilder {
         let index_handler = self.custom_index.unwrap_or_else(|| web::get().to(index));
         let mut admin_scope = web::scope(self.actix_admin.configuration.base_path)
             .route("/", index_handler)
+            .route("/search", web::get().to(global_search))
             .default_service(web::to(not_found));
 
         for (_, scope) in self.scopes {
@@ -301,6 +356,16 @@ impl ActixAdminBuilder {
     }
 }
 
+fn list_entities<'a, E: ActixAdminViewModelTrait>(
+    db: &'a sea_orm::DatabaseConnection,
+    params: &'a ActixAdminViewModelParams,
+) -> futures_util::future::LocalBoxFuture<
+    'a,
+    Result<(Option<u64>, Vec<ActixAdminModel>), ActixAdminError>,
+> {
+    E::list(db, params)
+}
+
 impl ActixAdminBuilder {
     /// Insert `element` under `category_name` in the menu, creating the category
     /// entry if it doesn't exist. If `dedupe` is true, skip elements already present.
@@ -0,0 +1,175 @@
+//! Checks behind the declarative validation attributes of
+//! `#[actix_admin(...)]`: `min_length`, `max_length`, `min`, `max`,
+//! `regex`, `email`, `url` and `one_of`.
+//!
+//! `DeriveActixAdminModel` calls them from `validate_model` with the parsed
+//! value of a field; each returns the error message of a violation.
+
+use regex::Regex;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Compile the `regex` of a field, anchored like the HTML `pattern`
+/// attribute so that it has to match the whole value. The derive macro
+/// rejects an invalid expression at compile time.
+pub fn parse_pattern(field_name: &str, pattern: &str) -> Regex {
+    Regex::new(&format!("^(?:{pattern})$"))
+        .unwrap_or_else(|err| panic!("invalid regex for field `{field_name}`: {err}"))
+}
+
+/// Parse the `min` / `max` bound of a field into the field's type. The
+/// derive macro rejects a bound that doesn't parse at compile time.
+pub fn parse_bound<T: FromStr>(field_name: &str, bound: &str) -> T {
+    bound
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid bound `{bound}` for field `{field_name}`"))
+}
+
+pub fn length_error(
+    value: &str,
+    min_length: Option<u32>,
+    max_length: Option<u32>,
+) -> Option<String> {
+    let length = value.chars().count();
+    match (min_length, max_length) {
+        (Some(min), _) if length < min as usize => {
+            Some(format!("Must be at least {min} characters"))
+        }
+        (_, Some(max)) if length > max as usize => {
+            Some(format!("Must be at most {max} characters"))
+        }
+        _ => None,
+    }
+}
+
+pub fn range_error<T: PartialOrd + Display>(
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Option<String> {
+    match (min, max) {
+        (Some(min), _) if *value < min => Some(format!("Must be at least {min}")),
+        (_, Some(max)) if *value > max => Some(format!("Must be at most {max}")),
+        _ => None,
+    }
+}
+
+pub fn pattern_error(value: &str, pattern: &Regex) -> Option<String> {
+    (!pattern.is_match(value)).then(|| "Invalid format".to_string())
+}
+
+/// A single `@` between a non-empty local part and a domain with a dot,
+/// without whitespace.
+pub fn email_error(value: &str) -> Option<String> {
+    let valid = match value.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').count() > 1
+                && domain.split('.').all(|label| !label.is_empty())
+                && !value.chars().any(char::is_whitespace)
+        }
+        None => false,
+    };
+    (!valid).then(|| "Invalid email address".to_string())
+}
+
+/// An absolute `scheme://host...` URL without whitespace.
+pub fn url_error(value: &str) -> Option<String> {
+    let valid = match value.split_once("://") {
+        Some((scheme, rest)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !rest.is_empty()
+                && !rest.starts_with('/')
+                && !value.chars().any(char::is_whitespace)
+        }
+        None => false,
+    };
+    (!valid).then(|| "Invalid URL".to_string())
+}
+
+pub fn one_of_error(value: &str, allowed: &[&str]) -> Option<String> {
+    (!allowed.contains(&value)).then(|| format!("Must be one of: {}", allowed.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_is_counted_in_characters() {
+        assert_eq!(length_error("äöü", Some(3), Some(3)), None);
+        assert_eq!(
+            length_error("ab", Some(3), None),
+            Some("Must be at least 3 characters".to_string())
+        );
+        assert_eq!(
+            length_error("abcd", None, Some(3)),
+            Some("Must be at most 3 characters".to_string())
+        );
+    }
+
+    #[test]
+    fn ranges_compare_the_parsed_values() {
+        let min: i32 = parse_bound("views", "10");
+        assert_eq!(range_error(&10, Some(min), None), None);
+        assert_eq!(
+            range_error(&9, Some(min), None),
+            Some("Must be at least 10".to_string())
+        );
+
+        let max: chrono::NaiveDate = parse_bound("insert_date", "2024-12-31");
+        assert!(range_error(&parse_bound("d", "2025-01-01"), None, Some(max)).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bound `ten` for field `views`")]
+    fn invalid_bounds_panic() {
+        let _: i32 = parse_bound("views", "ten");
+    }
+
+    #[test]
+    fn patterns_match_the_whole_value() {
+        let pattern = parse_pattern("code", "[A-Z]{3}|[0-9]{3}");
+        assert_eq!(pattern_error("ABC", &pattern), None);
+        assert_eq!(pattern_error("123", &pattern), None);
+        assert!(pattern_error("ABC1", &pattern).is_some());
+    }
+
+    #[test]
+    fn emails_and_urls() {
+        assert_eq!(email_error("me@example.com"), None);
+        for invalid in [
+            "me",
+            "me@",
+            "@example.com",
+            "me@example",
+            "me@a..com",
+            "m e@x.com",
+        ] {
+            assert!(email_error(invalid).is_some(), "{invalid}");
+        }
+        assert_eq!(url_error("https://example.com/a?b=c"), None);
+        for invalid in [
+            "example.com",
+            "https://",
+            "https:///path",
+            "1http://x",
+            "http://a b",
+        ] {
+            assert!(url_error(invalid).is_some(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn one_of_lists_the_allowed_values() {
+        assert_eq!(one_of_error("draft", &["draft", "published"]), None);
+        assert_eq!(
+            one_of_error("deleted", &["draft", "published"]),
+            Some("Must be one of: draft, published".to_string())
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Typed values of the `DateRange` / `DateTimeRange` filter types.
+//!
+//! The sidebar renders from / to inputs plus a preset select, submitted as
+//! `filter_<name>__from`, `filter_<name>__to` and `filter_<name>__preset`.
+//! `parse_filters_from_query` folds them into the filter's single value,
+//! either a preset name (`last_7_days`) or `from..to` with either side
+//! optional (`2024-01-01..`, `..2024-02-01T10:00`), which
+//! [`ActixAdminDateRange::parse`] turns back into bounds for the filter
+//! callback.
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use serde_derive::Serialize;
+
+/// Separates the bounds in a range filter value.
+pub const DATE_RANGE_SEPARATOR: &str = "..";
+
+/// Ranges relative to the current day, stored by name so that bookmarked
+/// lists stay relative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActixAdminDateRangePreset {
+    Today,
+    Last7Days,
+    ThisMonth,
+}
+
+impl ActixAdminDateRangePreset {
+    pub const ALL: [Self; 3] = [Self::Today, Self::Last7Days, Self::ThisMonth];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Today => "today",
+            Self::Last7Days => "last_7_days",
+            Self::ThisMonth => "this_month",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| preset.as_str() == name)
+    }
+
+    /// The first day of the range and the day after its last day.
+    fn days(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let tomorrow = today + Duration::days(1);
+        match self {
+            Self::Today => (today, tomorrow),
+            Self::Last7Days => (today - Duration::days(6), tomorrow),
+            Self::ThisMonth => {
+                let first = today.with_day(1).unwrap_or(today);
+                let next = first
+                    .checked_add_months(chrono::Months::new(1))
+                    .unwrap_or(tomorrow);
+                (first, next)
+            }
+        }
+    }
+}
+
+/// The range picked in a `DateRange` / `DateTimeRange` filter: rows with
+/// `from <= value < to`. Either bound may be open. A date entered as the
+/// upper bound includes that whole day, so `to` is the following midnight;
+/// a date and time is used as is.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ActixAdminDateRange {
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    /// The preset the range was resolved from, if any.
+    pub preset: Option<ActixAdminDateRangePreset>,
+}
+
+impl ActixAdminDateRange {
+    /// Parse a filter value relative to the local current day. `None` for a
+    /// blank or malformed value, which the filter then ignores.
+    pub fn from_value(value: &str) -> Option<Self> {
+        Self::parse(value, Local::now().date_naive())
+    }
+
+    /// Parse a filter value, resolving presets relative to `today`.
+    pub fn parse(value: &str, today: NaiveDate) -> Option<Self> {
+        let value = value.trim();
+        if let Some(preset) = ActixAdminDateRangePreset::from_name(value) {
+            let (from, to) = preset.days(today);
+            return Some(ActixAdminDateRange {
+                from: Some(from.and_time(NaiveTime::MIN)),
+                to: Some(to.and_time(NaiveTime::MIN)),
+                preset: Some(preset),
+            });
+        }
+        let (from, to) = value.split_once(DATE_RANGE_SEPARATOR)?;
+        let range = ActixAdminDateRange {
+            from: parse_bound(from, false)?,
+            to: parse_bound(to, true)?,
+            preset: None,
+        };
+        (range.from.is_some() || range.to.is_some()).then_some(range)
+    }
+
+    /// Encode sidebar inputs as a filter value; a preset wins over the
+    /// bounds. `None` when nothing was entered.
+    pub fn encode(from: &str, to: &str, preset: &str) -> Option<String> {
+        let (from, to, preset) = (from.trim(), to.trim(), preset.trim());
+        if !preset.is_empty() {
+            Some(preset.to_string())
+        } else if from.is_empty() && to.is_empty() {
+            None
+        } else {
+            Some(format!("{from}{DATE_RANGE_SEPARATOR}{to}"))
+        }
+    }
+}
+
+/// `Some(None)` for an open bound, `None` when malformed.
+fn parse_bound(bound: &str, upper: bool) -> Option<Option<NaiveDateTime>> {
+    let bound = bound.trim();
+    if bound.is_empty() {
+        return Some(None);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(bound, "%Y-%m-%d") {
+        let date = if upper {
+            date + Duration::days(1)
+        } else {
+            date
+        };
+        return Some(Some(date.and_time(NaiveTime::MIN)));
+    }
+    ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(bound, format).ok())
+        .map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn midnight(y: i32, m: u32, d: u32) -> Option<NaiveDateTime> {
+        Some(day(y, m, d).and_time(NaiveTime::MIN))
+    }
+
+    #[test]
+    fn parses_bounds() {
+        let today = day(2024, 3, 15);
+        let range = ActixAdminDateRange::parse("2024-01-01..2024-01-31", today).unwrap();
+        assert_eq!(range.from, midnight(2024, 1, 1));
+        assert_eq!(range.to, midnight(2024, 2, 1));
+
+        let range = ActixAdminDateRange::parse("..2024-01-31T10:30", today).unwrap();
+        assert_eq!(range.from, None);
+        assert_eq!(range.to, day(2024, 1, 31).and_hms_opt(10, 30, 0));
+
+        assert!(ActixAdminDateRange::parse("..", today).is_none());
+        assert!(ActixAdminDateRange::parse("2024-01-01", today).is_none());
+        assert!(ActixAdminDateRange::parse("yesterday..", today).is_none());
+    }
+
+    #[test]
+    fn resolves_presets() {
+        let today = day(2024, 2, 15);
+        let range = ActixAdminDateRange::parse("today", today).unwrap();
+        assert_eq!(
+            (range.from, range.to),
+            (midnight(2024, 2, 15), midnight(2024, 2, 16))
+        );
+        let range = ActixAdminDateRange::parse("last_7_days", today).unwrap();
+        assert_eq!(
+            (range.from, range.to),
+            (midnight(2024, 2, 9), midnight(2024, 2, 16))
+        );
+        let range = ActixAdminDateRange::parse("this_month", today).unwrap();
+        assert_eq!(
+            (range.from, range.to),
+            (midnight(2024, 2, 1), midnight(2024, 3, 1))
+        );
+        assert_eq!(range.preset, Some(ActixAdminDateRangePreset::ThisMonth));
+    }
+
+    #[test]
+    fn encodes_inputs() {
+        assert_eq!(
+            ActixAdminDateRange::encode("2024-01-01", "", "").as_deref(),
+            Some("2024-01-01..")
+        );
+        assert_eq!(
+            ActixAdminDateRange::encode("2024-01-01", "", "today").as_deref(),
+            Some("today")
+        );
+        assert_eq!(ActixAdminDateRange::encode(" ", "", ""), None);
+    }
+}
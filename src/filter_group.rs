@@ -0,0 +1,172 @@
+//! Advanced list filters: nested AND / OR groups of
+//! `(field, operator, value)` conditions.
+//!
+//! A group travels in the list querystring as JSON in the `where` parameter,
+//! in the shape edited by the query builder in the filter aside:
+//!
+//! ```json
+//! {"combinator": "and", "rules": [
+//!     {"field": "title", "operator": "contains", "value": "rust"},
+//!     {"combinator": "or", "rules": [
+//!         {"field": "id", "operator": "lt", "value": "10"},
+//!         {"field": "text", "operator": "is_null"}
+//!     ]}
+//! ]}
+//! ```
+//!
+//! The generated `list_model` compiles it to a SeaORM [`Condition`] against
+//! the entity's own fields, so unknown field names are rejected.
+
+use sea_orm::{ColumnTrait, Condition, DbBackend};
+use serde::Deserializer;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::model::column_filter_condition;
+use crate::view_model::ActixAdminFilterOperator;
+use crate::ActixAdminError;
+
+/// How the rules of a group are combined.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActixAdminFilterCombinator {
+    #[default]
+    And,
+    Or,
+}
+
+/// A group of rules combined with AND or OR.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActixAdminFilterGroup {
+    #[serde(default)]
+    pub combinator: ActixAdminFilterCombinator,
+    pub rules: Vec<ActixAdminFilterRule>,
+}
+
+/// A rule of a group: either a nested group or a single condition.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActixAdminFilterRule {
+    Group(ActixAdminFilterGroup),
+    Condition(ActixAdminFilterCondition),
+}
+
+/// `field operator value`, with the same semantics as the structured list
+/// search; see [`column_filter_condition`]. An empty value matches
+/// everything, except for the `is_null` / `is_not_null` operators which
+/// take none.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActixAdminFilterCondition {
+    pub field: String,
+    #[serde(deserialize_with = "deserialize_operator")]
+    pub operator: ActixAdminFilterOperator,
+    #[serde(default)]
+    pub value: Option<String>,
+}
+
+/// Accept the short operator names of the sidebar (`gte`) as well as the
+/// serialized ones (`greater_equals`).
+fn deserialize_operator<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ActixAdminFilterOperator, D::Error> {
+    let name = <String as serde::Deserialize>::deserialize(deserializer)?;
+    name.parse()
+        .map_err(|_| serde::de::Error::custom(format!("unknown operator `{name}`")))
+}
+
+impl ActixAdminFilterGroup {
+    /// Parse the `where` querystring parameter. Fails with a message for the
+    /// user on malformed JSON.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        serde_json::from_str(input).map_err(|e| format!("Invalid advanced filter: {e}"))
+    }
+
+    /// Whether the group has no conditions at all, in any nested group.
+    pub fn is_empty(&self) -> bool {
+        self.rules.iter().all(|rule| match rule {
+            ActixAdminFilterRule::Group(group) => group.is_empty(),
+            ActixAdminFilterRule::Condition(_) => false,
+        })
+    }
+
+    /// Compile the group into a condition. `column` maps a field name to its
+    /// column, or `None` for names that may not be filtered on. Empty groups
+    /// match everything.
+    pub fn condition<C: ColumnTrait>(
+        &self,
+        column: &impl Fn(&str) -> Option<C>,
+        backend: DbBackend,
+    ) -> Result<Condition, ActixAdminError> {
+        if self.is_empty() {
+            return Ok(Condition::all());
+        }
+        let mut cond = match self.combinator {
+            ActixAdminFilterCombinator::And => Condition::all(),
+            ActixAdminFilterCombinator::Or => Condition::any(),
+        };
+        for rule in &self.rules {
+            let rule_cond = match rule {
+                ActixAdminFilterRule::Group(group) if group.is_empty() => continue,
+                ActixAdminFilterRule::Group(group) => group.condition(column, backend)?,
+                ActixAdminFilterRule::Condition(c) => {
+                    let col = column(&c.field).ok_or_else(|| {
+                        ActixAdminError::bad_request(format!("Unknown filter field `{}`", c.field))
+                    })?;
+                    let value = c.value.as_deref().filter(|v| !v.is_empty());
+                    column_filter_condition(col, &c.operator, value, backend)?
+                }
+            };
+            cond = cond.add(rule_cond);
+        }
+        Ok(cond)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_groups() {
+        let group = ActixAdminFilterGroup::parse(
+            r#"{"combinator":"or","rules":[
+                {"field":"title","operator":"contains","value":"rust"},
+                {"combinator":"and","rules":[{"field":"id","operator":"gte","value":"3"}]},
+                {"field":"text","operator":"is_null"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(group.combinator, ActixAdminFilterCombinator::Or);
+        assert_eq!(group.rules.len(), 3);
+        match &group.rules[1] {
+            ActixAdminFilterRule::Group(inner) => match &inner.rules[0] {
+                ActixAdminFilterRule::Condition(c) => {
+                    assert_eq!(c.operator, ActixAdminFilterOperator::GreaterEquals)
+                }
+                rule => panic!("expected a condition, got {rule:?}"),
+            },
+            rule => panic!("expected a group, got {rule:?}"),
+        }
+        match &group.rules[2] {
+            ActixAdminFilterRule::Condition(c) => assert!(c.value.is_none()),
+            rule => panic!("expected a condition, got {rule:?}"),
+        }
+    }
+
+    #[test]
+    fn empty_groups_are_empty() {
+        let group =
+            ActixAdminFilterGroup::parse(r#"{"rules":[{"combinator":"or","rules":[]}]}"#).unwrap();
+        assert_eq!(group.combinator, ActixAdminFilterCombinator::And);
+        assert!(group.is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(ActixAdminFilterGroup::parse("not json").is_err());
+        assert!(ActixAdminFilterGroup::parse(
+            r#"{"rules":[{"field":"id","operator":"between","value":"1"}]}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,313 @@
+//! Validation of `Json` fields against a JSON Schema.
+//!
+//! Covers the keywords that describe the shape of configuration blobs:
+//! `type`, `enum`, `const`, `properties`, `required`,
+//! `additionalProperties`, `items`, `minItems`, `maxItems`, `minLength`,
+//! `maxLength`, `pattern`, `minimum`, `maximum`, `exclusiveMinimum`,
+//! `exclusiveMaximum`, `allOf` and `anyOf`. Other keywords are ignored.
+//! Errors are reported per JSON Pointer (`/servers/0/port`), see
+//! [`json_schema_errors`].
+
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Parse the schema attached to a field with `json_schema = "..."`.
+/// Panics on invalid JSON.
+pub fn parse_json_schema(field_name: &str, schema: &str) -> Value {
+    serde_json::from_str(schema)
+        .unwrap_or_else(|err| panic!("invalid JSON schema for field `{field_name}`: {err}"))
+}
+
+/// Validate `value` against `schema`, returning `(pointer, message)` for
+/// every violation. The pointer of the root is `""`.
+pub fn validate_json_schema(schema: &Value, value: &Value) -> Vec<(String, String)> {
+    let mut errors = Vec::new();
+    validate_at(schema, value, "", &mut errors);
+    errors
+}
+
+/// The errors of `value` keyed for [`crate::model::ActixAdminModel::errors`]:
+/// `<field_name><pointer>` per violation (`config/retries`), plus a summary
+/// of all violations under `<field_name>` so the form flags the field.
+pub fn json_schema_errors(
+    field_name: &str,
+    schema: &Value,
+    value: &Value,
+) -> HashMap<String, String> {
+    let violations = validate_json_schema(schema, value);
+    let mut errors = HashMap::new();
+    if violations.is_empty() {
+        return errors;
+    }
+
+    let summary = violations
+        .iter()
+        .map(|(pointer, message)| match pointer.as_str() {
+            "" => message.to_string(),
+            pointer => format!("{pointer}: {message}"),
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    for (pointer, message) in violations {
+        errors
+            .entry(format!("{field_name}{pointer}"))
+            .or_insert(message);
+    }
+    errors.insert(field_name.to_string(), summary);
+    errors
+}
+
+fn validate_at(schema: &Value, value: &Value, pointer: &str, errors: &mut Vec<(String, String)>) {
+    // `true` / `{}` accept anything, `false` nothing.
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => {
+            errors.push((pointer.to_string(), "is not allowed".to_string()));
+            return;
+        }
+        Value::Object(schema) => schema,
+        _ => return,
+    };
+    let mut error = |message: String| errors.push((pointer.to_string(), message));
+
+    if let Some(expected) = schema.get("type") {
+        let types: Vec<&str> = match expected {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !types.is_empty() && !types.iter().any(|name| has_type(value, name)) {
+            error(format!("must be of type {}", types.join(" or ")));
+            // The other keywords would only repeat the type mismatch.
+            return;
+        }
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            error(format!("must be one of {}", allowed.join(", ")));
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            error(format!("must be {expected}"));
+        }
+    }
+
+    match value {
+        Value::String(text) => {
+            let length = text.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if length < min {
+                    error(format!("must be at least {min} characters long"));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if length > max {
+                    error(format!("must be at most {max} characters long"));
+                }
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                match Regex::new(pattern) {
+                    Ok(regex) if !regex.is_match(text) => {
+                        error(format!("must match the pattern {pattern}"))
+                    }
+                    Ok(_) => {}
+                    Err(_) => error(format!("has an invalid pattern {pattern} in the schema")),
+                }
+            }
+        }
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            let bound = |keyword: &str| schema.get(keyword).and_then(Value::as_f64);
+            if let Some(min) = bound("minimum").filter(|min| number < *min) {
+                error(format!("must be at least {min}"));
+            }
+            if let Some(max) = bound("maximum").filter(|max| number > *max) {
+                error(format!("must be at most {max}"));
+            }
+            if let Some(min) = bound("exclusiveMinimum").filter(|min| number <= *min) {
+                error(format!("must be greater than {min}"));
+            }
+            if let Some(max) = bound("exclusiveMaximum").filter(|max| number >= *max) {
+                error(format!("must be less than {max}"));
+            }
+        }
+        Value::Array(items) => {
+            let length = items.len() as u64;
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if length < min {
+                    error(format!("must have at least {min} items"));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if length > max {
+                    error(format!("must have at most {max} items"));
+                }
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_at(item_schema, item, &format!("{pointer}/{index}"), errors);
+                }
+            }
+        }
+        Value::Object(object) => {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !object.contains_key(name) {
+                        errors.push((child_pointer(pointer, name), "is required".to_string()));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (name, property) in object {
+                let property_pointer = child_pointer(pointer, name);
+                match properties.and_then(|properties| properties.get(name)) {
+                    Some(property_schema) => {
+                        validate_at(property_schema, property, &property_pointer, errors)
+                    }
+                    None => {
+                        if let Some(additional) = schema.get("additionalProperties") {
+                            validate_at(additional, property, &property_pointer, errors);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(Value::Array(schemas)) = schema.get("allOf") {
+        for schema in schemas {
+            validate_at(schema, value, pointer, errors);
+        }
+    }
+    if let Some(Value::Array(schemas)) = schema.get("anyOf") {
+        let matches_any = schemas
+            .iter()
+            .any(|schema| validate_json_schema(schema, value).is_empty());
+        if !matches_any {
+            errors.push((
+                pointer.to_string(),
+                "must match at least one of the allowed schemas".to_string(),
+            ));
+        }
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+/// Append `name` to a JSON Pointer, escaping `~` and `/` (RFC 6901).
+fn child_pointer(pointer: &str, name: &str) -> String {
+    format!("{pointer}/{}", name.replace('~', "~0").replace('/', "~1"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["name", "retries"],
+            "additionalProperties": false,
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "retries": { "type": "integer", "minimum": 0, "maximum": 10 },
+                "mode": { "enum": ["fast", "safe"] },
+                "servers": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": { "port": { "type": "integer", "exclusiveMaximum": 65536 } }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn valid_values_have_no_errors() {
+        let value =
+            json!({ "name": "a", "retries": 3, "mode": "safe", "servers": [{ "port": 80 }] });
+        assert!(validate_json_schema(&schema(), &value).is_empty());
+    }
+
+    #[test]
+    fn errors_are_reported_per_pointer() {
+        let value = json!({
+            "name": "",
+            "retries": -1,
+            "mode": "slow",
+            "servers": [{ "port": 80 }, { "port": 70000 }],
+            "a/b": true
+        });
+        let mut errors = validate_json_schema(&schema(), &value);
+        errors.sort();
+        assert_eq!(
+            errors,
+            vec![
+                ("/a~1b".to_string(), "is not allowed".to_string()),
+                (
+                    "/mode".to_string(),
+                    "must be one of \"fast\", \"safe\"".to_string()
+                ),
+                (
+                    "/name".to_string(),
+                    "must be at least 1 characters long".to_string()
+                ),
+                ("/retries".to_string(), "must be at least 0".to_string()),
+                (
+                    "/servers/1/port".to_string(),
+                    "must be less than 65536".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn type_mismatches_and_missing_properties() {
+        assert_eq!(
+            validate_json_schema(&schema(), &json!([])),
+            vec![(String::new(), "must be of type object".to_string())]
+        );
+        assert_eq!(
+            validate_json_schema(&schema(), &json!({ "name": "a" })),
+            vec![("/retries".to_string(), "is required".to_string())]
+        );
+        assert_eq!(
+            validate_json_schema(&json!({ "type": ["string", "null"] }), &json!(null)),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn field_errors_are_keyed_by_path_and_summarised() {
+        let errors =
+            json_schema_errors("config", &schema(), &json!({ "name": "a", "retries": 11 }));
+        assert_eq!(errors.get("config/retries").unwrap(), "must be at most 10");
+        assert_eq!(
+            errors.get("config").unwrap(),
+            "/retries: must be at most 10"
+        );
+        assert!(
+            json_schema_errors("config", &schema(), &json!({ "name": "a", "retries": 1 }))
+                .is_empty()
+        );
+    }
+}
@@ -12,6 +12,7 @@ use actix_web::{
 };
 use async_trait::async_trait;
 use derive_more::{Display, Error};
+use futures_util::future::LocalBoxFuture;
 use sea_orm::DatabaseConnection;
 use serde_derive::Serialize;
 use std::collections::{BTreeMap, HashMap};
@@ -19,31 +20,57 @@ use std::fmt::{self, Display as FmtDisplay};
 use tera::Tera;
 
 pub mod builder;
+pub mod constraints;
 pub mod csrf;
+pub mod date_range;
+pub mod filter_group;
+pub mod json_schema;
 pub mod model;
+pub mod password;
 pub mod routes;
+pub mod search_backend;
 pub mod tera_templates;
 pub mod view_model;
 
 pub mod prelude {
     pub use crate::builder::{ActixAdminBuilder, ActixAdminBuilderTrait};
+    pub use crate::constraints::{
+        email_error, length_error, one_of_error, parse_bound, parse_pattern, pattern_error,
+        range_error, url_error,
+    };
     pub use crate::csrf::{
         csrf_token_for, verify_csrf, CsrfError, CSRF_HEADER, CSRF_QUERY_PARAM, CSRF_SESSION_KEY,
     };
+    pub use crate::date_range::{ActixAdminDateRange, ActixAdminDateRangePreset};
+    pub use crate::filter_group::{
+        ActixAdminFilterCombinator, ActixAdminFilterCondition, ActixAdminFilterGroup,
+        ActixAdminFilterRule,
+    };
+    pub use crate::json_schema::{json_schema_errors, parse_json_schema, validate_json_schema};
     pub use crate::model::{
-        ActixAdminModel, ActixAdminModelFilter, ActixAdminModelFilterTrait,
-        ActixAdminModelFilterType, ActixAdminModelTrait, ActixAdminModelValidationTrait, FilterFn,
+        column_filter_condition, column_multi_filter_condition, keyset_condition, order_by_expr,
+        ActixAdminModel, ActixAdminModelAsyncValidationTrait, ActixAdminModelFilter,
+        ActixAdminModelFilterTrait, ActixAdminModelFilterType, ActixAdminModelHooksTrait,
+        ActixAdminModelTrait, ActixAdminModelValidationTrait, ActixAdminOperation, FilterFn,
     };
+    pub use crate::password::{hash_password_argon2, verify_password_argon2};
     pub use crate::routes::{
-        bulk_action, create_or_edit_post, get_admin_ctx, ActixAdminBulkActionDispatch, SortOrder,
+        bulk_action, create_or_edit_post, get_admin_ctx, ActixAdminBulkActionDispatch, SortColumn,
+        SortOrder,
     };
+    pub use crate::search_backend::ActixAdminSearchBackend;
     pub use crate::view_model::{
-        ActixAdminBulkAction, ActixAdminFilterOperator, ActixAdminPrimaryKey, ActixAdminViewModel,
+        ActixAdminBulkAction, ActixAdminComputeFn, ActixAdminComputedColumn,
+        ActixAdminComputedValue, ActixAdminCursor, ActixAdminFieldCondition,
+        ActixAdminFieldDefaultFn, ActixAdminFieldset, ActixAdminFilterOperator, ActixAdminFormTab,
+        ActixAdminLookupParams, ActixAdminPrimaryKey, ActixAdminViewModel,
         ActixAdminViewModelField, ActixAdminViewModelFieldType, ActixAdminViewModelFilter,
         ActixAdminViewModelParams, ActixAdminViewModelSerializable, ActixAdminViewModelTrait,
     };
-    pub use crate::{hashmap, ActixAdminSelectListTrait};
-    pub use crate::{ActixAdmin, ActixAdminConfiguration, ActixAdminError, ActixAdminErrorType};
+    pub use crate::{hashmap, ActixAdminEnumSelectListTrait, ActixAdminSelectListTrait};
+    pub use crate::{
+        ActixAdmin, ActixAdminConfiguration, ActixAdminError, ActixAdminErrorType, ActixAdminListFn,
+    };
     pub use actix_admin_macros::{
         DeriveActixAdmin, DeriveActixAdminEnumSelectList, DeriveActixAdminModel,
         DeriveActixAdminModelSelectList, DeriveActixAdminViewModel,
@@ -64,6 +91,26 @@ macro_rules! hashmap {
     }}
 }
 
+/// The variants of an enum offered in a select list, implemented by
+/// `DeriveActixAdminEnumSelectList`. An option is submitted as
+/// [`to_select_value`](Self::to_select_value), the value stored in the
+/// database for `DeriveActiveEnum` enums, and shown as
+/// [`to_select_label`](Self::to_select_label), set per variant with
+/// `#[actix_admin(label = "...")]`.
+pub trait ActixAdminEnumSelectListTrait: Sized {
+    fn variants() -> Vec<Self>;
+
+    fn to_select_value(&self) -> String;
+
+    fn to_select_label(&self) -> String;
+
+    fn from_select_value(value: &str) -> Option<Self> {
+        Self::variants()
+            .into_iter()
+            .find(|variant| variant.to_select_value() == value)
+    }
+}
+
 // SelectListTrait
 #[async_trait]
 pub trait ActixAdminSelectListTrait {
@@ -94,6 +141,9 @@ pub struct ActixAdminConfiguration {
     /// deployment is behind a non-cookie-session auth flow and you do not want
     /// this protection (e.g. tests, an isolated intranet), set to `false`.
     pub enable_csrf: bool,
+    /// Hashes the non-empty values of `#[actix_admin(password)]` fields
+    /// before they are stored. Defaults to [`hash_password_argon2`].
+    pub hash_password: fn(&str) -> Result<String, ActixAdminError>,
 }
 
 impl Default for ActixAdminConfiguration {
@@ -110,14 +160,26 @@ impl Default for ActixAdminConfiguration {
             custom_css_paths: None,
             custom_js_paths: None,
             enable_csrf: true,
+            hash_password: hash_password_argon2,
         }
     }
 }
 
+/// [`ActixAdminViewModelTrait::list`] of a registered entity with the entity
+/// type erased, so routes spanning all entities (e.g. the global search) can
+/// query them by name.
+pub type ActixAdminListFn =
+    for<'a> fn(
+        &'a DatabaseConnection,
+        &'a ActixAdminViewModelParams,
+    )
+        -> LocalBoxFuture<'a, Result<(Option<u64>, Vec<ActixAdminModel>), ActixAdminError>>;
+
 #[derive(Clone)]
 pub struct ActixAdmin {
     pub entity_names: BTreeMap<String, Vec<ActixAdminMenuElement>>,
     pub view_models: HashMap<String, ActixAdminViewModel>,
+    pub entity_lists: HashMap<String, ActixAdminListFn>,
     pub card_grids: HashMap<String, Vec<Vec<String>>>,
     pub configuration: ActixAdminConfiguration,
     pub tera: Tera,
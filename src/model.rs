@@ -1,12 +1,23 @@
+use crate::date_range::ActixAdminDateRange;
 use crate::view_model::{
-    ActixAdminFilterOperator, ActixAdminViewModelFilter, ActixAdminViewModelParams,
+    ActixAdminCursor, ActixAdminFilterOperator, ActixAdminLookupParams,
+    ActixAdminViewModelFieldType, ActixAdminViewModelFilter, ActixAdminViewModelParams,
+};
+use crate::{
+    ActixAdminEnumSelectListTrait, ActixAdminError, ActixAdminErrorType, ActixAdminViewModelField,
+    SortOrder,
 };
-use crate::{ActixAdminError, ActixAdminErrorType, ActixAdminViewModelField};
 use actix_multipart::Multipart;
+use actix_session::Session;
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use futures_util::stream::StreamExt as _;
-use sea_orm::{DatabaseConnection, EntityTrait};
+use sea_orm::prelude::Decimal;
+use sea_orm::sea_query::{Alias, Expr, ExprTrait, LikeExpr};
+use sea_orm::{
+    ColumnTrait, ColumnType, Condition, ConnectionTrait, DatabaseConnection, DatabaseTransaction,
+    DbBackend, EntityTrait, Order, QueryOrder, Select, Value,
+};
 use serde_derive::Serialize;
 use std::collections::HashMap;
 use std::fs::File;
@@ -51,6 +62,57 @@ pub fn sanitize_upload_filename(raw: &str) -> String {
     }
 }
 
+/// Sanitize `raw_filename` and pick a path for it in `file_upload_folder`
+/// that doesn't overwrite an existing file.
+fn unique_upload_path(
+    file_upload_folder: &str,
+    raw_filename: &str,
+) -> Result<(String, PathBuf), ActixAdminError> {
+    let mut filename = sanitize_upload_filename(raw_filename);
+
+    let base = PathBuf::from(file_upload_folder);
+    let mut file_path = base.join(&filename);
+
+    // Avoid overwriting existing files by prefixing a timestamp.
+    if file_path.exists() {
+        let ts = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        filename = format!("{ts}_{filename}");
+        file_path = base.join(&filename);
+    }
+
+    // Defense in depth: reject any joined path that escapes the base.
+    let canonical_base = base.canonicalize().unwrap_or_else(|_| base.clone());
+    let parent = file_path.parent().unwrap_or(&base);
+    let canonical_parent = parent
+        .canonicalize()
+        .unwrap_or_else(|_| parent.to_path_buf());
+    if !canonical_parent.starts_with(&canonical_base) {
+        return Err(ActixAdminError::new(
+            ActixAdminErrorType::UploadError,
+            "Uploaded filename resolves outside the upload directory",
+        ));
+    }
+
+    Ok((filename, file_path))
+}
+
+/// Copy the uploaded file `filename` of `file_upload_folder` to a new file
+/// next to it and return the new filename.
+pub fn copy_upload(file_upload_folder: &str, filename: &str) -> Result<String, ActixAdminError> {
+    let source = PathBuf::from(file_upload_folder).join(sanitize_upload_filename(filename));
+    let (filename, file_path) = unique_upload_path(file_upload_folder, filename)?;
+    std::fs::copy(&source, &file_path).map_err(|err| {
+        ActixAdminError::new(
+            ActixAdminErrorType::UploadError,
+            format!("Could not copy uploaded file: {err}"),
+        )
+    })?;
+    Ok(filename)
+}
+
 #[async_trait]
 pub trait ActixAdminModelTrait {
     async fn list_model(
@@ -58,9 +120,13 @@ pub trait ActixAdminModelTrait {
         params: &ActixAdminViewModelParams,
         filter_values: HashMap<String, Option<String>>,
     ) -> Result<(Option<u64>, Vec<ActixAdminModel>), ActixAdminError>;
+    async fn lookup_model(
+        db: &DatabaseConnection,
+        params: &ActixAdminLookupParams,
+    ) -> Result<Vec<(String, String)>, ActixAdminError>;
     fn get_fields() -> &'static [ActixAdminViewModelField];
     fn validate_model(model: &mut ActixAdminModel);
-    async fn load_foreign_keys(models: &mut [ActixAdminModel], db: &DatabaseConnection);
+    async fn load_foreign_keys<C: ConnectionTrait>(models: &mut [ActixAdminModel], db: &C);
 }
 
 pub trait ActixAdminModelValidationTrait<T> {
@@ -69,6 +135,96 @@ pub trait ActixAdminModelValidationTrait<T> {
     }
 }
 
+/// The write a model is validated for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ActixAdminOperation {
+    Create,
+    /// Edit of the entity with this primary key.
+    Edit(String),
+}
+
+/// Validation that needs the database or the session, e.g. uniqueness or
+/// rules across records. Runs after the field validation and
+/// [`ActixAdminModelValidationTrait::validate`]; the returned errors by field
+/// name are merged into [`ActixAdminModel::custom_errors`], an error already
+/// reported for a field taking precedence.
+#[async_trait(?Send)]
+pub trait ActixAdminModelAsyncValidationTrait {
+    async fn validate_async(
+        _db: &DatabaseConnection,
+        _session: &Session,
+        _operation: &ActixAdminOperation,
+        _model: &ActixAdminModel,
+    ) -> HashMap<String, String> {
+        HashMap::new()
+    }
+}
+
+/// Code run around every write of the admin: creates and edits from the
+/// forms, single and bulk deletes and the removal of an uploaded file. `id`
+/// is the primary key, `old` the stored entity and `new` the model written.
+///
+/// The hooks run in the transaction of the write. A `before_*` hook can
+/// change `new` or veto the write by returning an error; an error of any
+/// hook rolls the transaction back and is reported instead.
+#[async_trait(?Send)]
+pub trait ActixAdminModelHooksTrait {
+    async fn before_create(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        _new: &mut ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn after_create(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        _id: &str,
+        _new: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn before_update(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        _id: &str,
+        _old: &ActixAdminModel,
+        _new: &mut ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn after_update(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        _id: &str,
+        _old: &ActixAdminModel,
+        _new: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn before_delete(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        _id: &str,
+        _old: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+
+    async fn after_delete(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        _id: &str,
+        _old: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        Ok(())
+    }
+}
+
 /// A single filter registered on an entity via `ActixAdminModelFilterTrait`.
 ///
 /// The `filter` closure receives the current query, the user-provided
@@ -86,9 +242,10 @@ pub struct ActixAdminModelFilter<E: EntityTrait> {
     pub operators: Vec<ActixAdminFilterOperator>,
 }
 
-/// Storage for the filter callback. Two shapes are supported so that simple
-/// value-only filters remain a one-liner, while operator-aware filters can
-/// react to the user's chosen comparator.
+/// Storage for the filter callback. Simple value-only filters remain a
+/// one-liner, operator-aware filters can react to the user's chosen
+/// comparator, range filters receive the parsed range and multi-value
+/// filters every selected value.
 #[allow(clippy::type_complexity)]
 pub enum FilterFn<E: EntityTrait> {
     ValueOnly(fn(sea_orm::Select<E>, Option<String>) -> sea_orm::Select<E>),
@@ -99,30 +256,381 @@ pub enum FilterFn<E: EntityTrait> {
             Option<ActixAdminFilterOperator>,
         ) -> sea_orm::Select<E>,
     ),
+    Range(fn(sea_orm::Select<E>, Option<ActixAdminDateRange>) -> sea_orm::Select<E>),
+    Multi(fn(sea_orm::Select<E>, Vec<String>) -> sea_orm::Select<E>),
 }
 
 impl<E: EntityTrait> FilterFn<E> {
     /// Apply the underlying callback to `query`. Operator-only closures
-    /// receive `operator`; value-only closures ignore it.
+    /// receive `operator`; value-only closures ignore it. Range closures get
+    /// `value` parsed, or `None` when it is blank or malformed. Multi-value
+    /// closures get `selected`, the values of a repeated filter key.
     pub fn apply(
         &self,
         query: sea_orm::Select<E>,
         value: Option<String>,
         operator: Option<ActixAdminFilterOperator>,
+        selected: Vec<String>,
     ) -> sea_orm::Select<E> {
         match self {
             FilterFn::ValueOnly(f) => f(query, value),
             FilterFn::WithOp(f) => f(query, value, operator),
+            FilterFn::Range(f) => f(
+                query,
+                value.as_deref().and_then(ActixAdminDateRange::from_value),
+            ),
+            FilterFn::Multi(f) => f(query, selected),
+        }
+    }
+}
+
+/// Rewrite a user-typed number into the `1234.5` form `FromStr` expects.
+/// Spaces, underscores and apostrophes group digits; when both `,` and `.`
+/// occur the last one is the decimal separator, a single `,` alone is one
+/// (`1,5`) and repeated separators group thousands (`1.234.567`).
+///
+/// `None` for a single `,` followed by exactly three digits (`1,234`),
+/// which is a thousand in some locales and about one in others.
+pub fn normalize_number(value: &str) -> Option<String> {
+    let digits: String = value
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '\'' | '\u{a0}' | '\u{202f}'))
+        .collect();
+    let decimal_separator = match (digits.rfind(','), digits.rfind('.')) {
+        (Some(comma), Some(dot)) => Some(if comma > dot { ',' } else { '.' }),
+        (Some(comma), None) if digits.matches(',').count() == 1 => {
+            let fraction = &digits[comma + 1..];
+            if fraction.len() == 3 && fraction.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            Some(',')
+        }
+        (None, Some(_)) if digits.matches('.').count() == 1 => Some('.'),
+        _ => None,
+    };
+    Some(
+        digits
+            .chars()
+            .filter_map(|c| match c {
+                ',' | '.' if Some(c) == decimal_separator => Some('.'),
+                ',' | '.' => None,
+                c => Some(c),
+            })
+            .collect(),
+    )
+}
+
+/// `column LIKE '%value%'`, with the `%`, `_` and `\` of `value` matched
+/// literally rather than as wildcards.
+pub fn column_contains<C: ColumnTrait>(column: C, value: &str) -> Expr {
+    column.like(contains_pattern(value))
+}
+
+/// The negation of [`column_contains`], which also holds where `column` is
+/// `NULL`.
+pub fn column_not_contains<C: ColumnTrait>(column: C, value: &str) -> Condition {
+    Condition::any()
+        .add(column.is_null())
+        .add(column.not_like(contains_pattern(value)))
+}
+
+fn contains_pattern(value: &str) -> LikeExpr {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    LikeExpr::new(format!("%{escaped}%")).escape('\\')
+}
+
+/// Parse a time of day with or without seconds (`14:30`, `14:30:15.5`).
+fn parse_time(value: &str) -> chrono::ParseResult<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M:%S%.f")
+        .or_else(|_| NaiveTime::parse_from_str(value.trim(), "%H:%M"))
+}
+
+/// The 16 bytes of a UUID written as 32 hex digits, with or without
+/// hyphens.
+fn parse_uuid(value: &str) -> Option<[u8; 16]> {
+    let hex: Vec<u8> = value.bytes().filter(|b| *b != b'-').collect();
+    if hex.len() != 32 || value.len() - hex.len() > 4 {
+        return None;
+    }
+    let mut bytes = [0u8; 16];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Build the `WHERE` clause that continues a keyset scan past `cursor`.
+///
+/// Rows are displayed ordered by the `(column, order, value)` triples of
+/// `sort` in turn, with ties broken by `primary_key` ascending (unless it is
+/// one of the sort columns). The values and `primary_key_value` are read
+/// from the boundary row the cursor points at. `NULL`s follow the default
+/// ordering of `backend`: before every value on SQLite and MySQL, after it
+/// on Postgres.
+pub fn keyset_condition<C: ColumnTrait>(
+    sort: &[(C, SortOrder, Value)],
+    primary_key: C,
+    primary_key_value: Value,
+    cursor: &ActixAdminCursor,
+    backend: DbBackend,
+) -> Condition {
+    let forward = !cursor.is_backward();
+    let nulls_smallest = backend != DbBackend::Postgres;
+    // Rows past `value` on `column` in scan order, `None` if there are none.
+    let past = |column: C, ascending: bool, value: &Value| {
+        let scan_ascending = ascending == forward;
+        let nulls_last = column.def().is_null() && nulls_smallest != scan_ascending;
+        if *value == value.as_null() {
+            return (!nulls_last).then(|| Condition::all().add(column.is_not_null()));
+        }
+        let past = if scan_ascending {
+            column.gt(value.clone())
+        } else {
+            column.lt(value.clone())
+        };
+        Some(if nulls_last {
+            Condition::any().add(past).add(column.is_null())
+        } else {
+            Condition::all().add(past)
+        })
+    };
+
+    // Lexicographic: past the boundary on some column, equal on all before.
+    let mut condition = Condition::any();
+    let mut equal = Condition::all();
+    for (column, order, value) in sort {
+        if let Some(past) = past(*column, *order == SortOrder::Asc, value) {
+            condition = condition.add(equal.clone().add(past));
+        }
+        if column.as_str() == primary_key.as_str() {
+            return condition;
         }
+        equal = equal.add(if *value == value.as_null() {
+            column.is_null()
+        } else {
+            column.eq(value.clone())
+        });
+    }
+    match past(primary_key, true, &primary_key_value) {
+        Some(past) => condition.add(equal.add(past)),
+        None => condition,
+    }
+}
+
+/// Order `query` by the raw SQL expression of a sortable computed column,
+/// see [`crate::ActixAdminComputedColumn::sort_expr`].
+pub fn order_by_expr<E: EntityTrait>(
+    query: Select<E>,
+    sort_expr: &str,
+    ascending: bool,
+) -> Select<E> {
+    let order = if ascending { Order::Asc } else { Order::Desc };
+    query.order_by(Expr::cust(sort_expr.to_string()), order)
+}
+
+/// Build the condition for an ad-hoc `column <operator> value` filter, as
+/// produced by the structured list search (`title:rust`, `id>10`).
+///
+/// `value` is parsed according to the column type so that comparisons are
+/// numeric / boolean / chronological rather than textual; an unparseable
+/// value is a `BadRequest`. `Contains` / `NotContains` fall back to equality
+/// on non-text columns, `InList` takes a comma-separated list, and a missing
+/// value matches everything (like an empty sidebar filter).
+pub fn column_filter_condition<C: ColumnTrait>(
+    column: C,
+    operator: &ActixAdminFilterOperator,
+    value: Option<&str>,
+    backend: DbBackend,
+) -> Result<Condition, ActixAdminError> {
+    use ActixAdminFilterOperator::*;
+
+    let column_type = column.def().get_column_type().clone();
+    let is_text = matches!(
+        column_type,
+        ColumnType::Char(_) | ColumnType::String(_) | ColumnType::Text
+    );
+    let cond = Condition::all();
+    let value = match (operator, value) {
+        (IsNull, _) => return Ok(cond.add(column.is_null())),
+        (IsNotNull, _) => return Ok(cond.add(column.is_not_null())),
+        (_, None) => return Ok(cond),
+        (_, Some(value)) => value,
+    };
+    let typed = |value: &str| {
+        column_filter_value(column, &column_type, value.trim(), backend).map_err(|expected| {
+            ActixAdminError::bad_request(format!(
+                "Invalid value `{value}` for `{}`: expected {expected}",
+                column.as_str()
+            ))
+        })
+    };
+
+    let col = Expr::col(column.as_column_ref());
+    Ok(match operator {
+        Contains if is_text => cond.add(column_contains(column, value)),
+        NotContains if is_text => cond.add(column_not_contains(column, value)),
+        Equals | Contains => cond.add(col.eq(typed(value)?)),
+        NotEquals | NotContains => cond.add(col.ne(typed(value)?)),
+        GreaterThan => cond.add(col.gt(typed(value)?)),
+        LessThan => cond.add(col.lt(typed(value)?)),
+        GreaterEquals => cond.add(col.gte(typed(value)?)),
+        LessEquals => cond.add(col.lte(typed(value)?)),
+        InList => cond.add(
+            col.is_in(
+                value
+                    .split(',')
+                    .filter(|v| !v.trim().is_empty())
+                    .map(typed)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        ),
+        IsNull | IsNotNull => unreachable!("handled above"),
+    })
+}
+
+/// Build the condition for a filter on several values, as submitted by a
+/// multi-select: rows matching any of them, or for the negated operators
+/// (`NotEquals`, `NotContains`) rows matching none of them. See
+/// [`column_filter_condition`] for how each value is compared.
+pub fn column_multi_filter_condition<C: ColumnTrait>(
+    column: C,
+    operator: &ActixAdminFilterOperator,
+    values: &[String],
+    backend: DbBackend,
+) -> Result<Condition, ActixAdminError> {
+    use ActixAdminFilterOperator::*;
+
+    let mut cond = match operator {
+        NotEquals | NotContains => Condition::all(),
+        _ => Condition::any(),
+    };
+    for value in values {
+        cond = cond.add(column_filter_condition(
+            column,
+            operator,
+            Some(value),
+            backend,
+        )?);
     }
+    Ok(cond)
+}
+
+/// Convert a user-supplied filter value into an expression of the column's
+/// type, or describe what was expected. Dates and times stay strings (the
+/// crate is built without sea-orm's chrono support) but are validated, and
+/// cast on Postgres where a text parameter doesn't compare with a date.
+fn column_filter_value<C: ColumnTrait>(
+    column: C,
+    column_type: &ColumnType,
+    value: &str,
+    backend: DbBackend,
+) -> Result<Expr, &'static str> {
+    let cast = |type_name: &str| {
+        let expr = Expr::val(value);
+        if backend == DbBackend::Postgres {
+            expr.cast_as(Alias::new(type_name))
+        } else {
+            expr
+        }
+    };
+    Ok(match column_type {
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::Integer
+        | ColumnType::BigInteger => Expr::val(value.parse::<i64>().map_err(|_| "an integer")?),
+        ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned
+        | ColumnType::Unsigned
+        | ColumnType::BigUnsigned => {
+            Expr::val(value.parse::<u64>().map_err(|_| "a non-negative integer")?)
+        }
+        ColumnType::Float | ColumnType::Double => Expr::val(
+            normalize_number(value)
+                .and_then(|value| value.parse::<f64>().ok())
+                .ok_or("a number, without an ambiguous `,`")?,
+        ),
+        // Bound exactly rather than rounded through an `f64`.
+        ColumnType::Decimal(_) | ColumnType::Money(_) => Expr::val(
+            normalize_number(value)
+                .and_then(|value| value.parse::<Decimal>().ok())
+                .ok_or("a number, without an ambiguous `,`")?,
+        ),
+        ColumnType::Boolean => Expr::val(match value.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "1" => true,
+            "false" | "no" | "0" => false,
+            _ => return Err("true or false"),
+        }),
+        ColumnType::Date => {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| "a date (YYYY-MM-DD)")?;
+            cast("date")
+        }
+        ColumnType::DateTime | ColumnType::Timestamp | ColumnType::TimestampWithTimeZone => {
+            let is_date = NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok();
+            // `datetime-local` inputs submit `YYYY-MM-DDTHH:MM`; normalize to
+            // the format SQLite stores so text comparisons stay correct.
+            let date_time = [
+                "%Y-%m-%d %H:%M:%S",
+                "%Y-%m-%dT%H:%M:%S",
+                "%Y-%m-%d %H:%M",
+                "%Y-%m-%dT%H:%M",
+            ]
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok());
+            let value = match date_time {
+                Some(date_time) => date_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+                None if is_date => value.to_string(),
+                None => return Err("a date (YYYY-MM-DD) or date and time (YYYY-MM-DD HH:MM:SS)"),
+            };
+            let expr = Expr::val(value);
+            match (backend, column_type) {
+                (DbBackend::Postgres, ColumnType::TimestampWithTimeZone) => {
+                    expr.cast_as(Alias::new("timestamptz"))
+                }
+                (DbBackend::Postgres, _) => expr.cast_as(Alias::new("timestamp")),
+                _ => expr,
+            }
+        }
+        ColumnType::Time => {
+            let time = parse_time(value).map_err(|_| "a time (HH:MM or HH:MM:SS)")?;
+            let expr = Expr::val(time.format("%H:%M:%S").to_string());
+            if backend == DbBackend::Postgres {
+                expr.cast_as(Alias::new("time"))
+            } else {
+                expr
+            }
+        }
+        // SQLite and MySQL store UUIDs as 16 bytes, Postgres as `uuid`.
+        ColumnType::Uuid => {
+            let bytes = parse_uuid(value).ok_or("a UUID")?;
+            if backend == DbBackend::Postgres {
+                Expr::val(value).cast_as(Alias::new("uuid"))
+            } else {
+                Expr::val(bytes.to_vec())
+            }
+        }
+        // Lets enum columns cast the text to the enum type, as `eq` does.
+        _ => column.save_as(Expr::val(value)),
+    })
 }
 
 #[derive(Clone, Debug, Serialize)]
 pub enum ActixAdminModelFilterType {
     Text,
+    Number,
     SelectList,
+    /// Picks any number of `values`; see [`ActixAdminModelFilter::with_multi`].
+    MultiSelectList,
     Date,
     DateTime,
+    Time,
+    /// From / to dates plus relative presets; see [`ActixAdminDateRange`].
+    DateRange,
+    /// From / to dates and times plus relative presets.
+    DateTimeRange,
     Checkbox,
     TomSelectSearch,
 }
@@ -166,6 +674,40 @@ impl<E: EntityTrait> ActixAdminModelFilter<E> {
         }
     }
 
+    /// Build a `DateRange` / `DateTimeRange` filter. The closure receives
+    /// the range the user picked, if any.
+    pub fn with_range(
+        name: impl Into<String>,
+        filter_type: ActixAdminModelFilterType,
+        filter: fn(sea_orm::Select<E>, Option<ActixAdminDateRange>) -> sea_orm::Select<E>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            filter_type,
+            filter: FilterFn::Range(filter),
+            values: None,
+            foreign_key: None,
+            operators: Vec::new(),
+        }
+    }
+
+    /// Build a multi-value filter, e.g. a `MultiSelectList`. The closure
+    /// receives every value picked, empty when none was.
+    pub fn with_multi(
+        name: impl Into<String>,
+        filter_type: ActixAdminModelFilterType,
+        filter: fn(sea_orm::Select<E>, Vec<String>) -> sea_orm::Select<E>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            filter_type,
+            filter: FilterFn::Multi(filter),
+            values: None,
+            foreign_key: None,
+            operators: Vec::new(),
+        }
+    }
+
     pub fn with_operators(mut self, operators: Vec<ActixAdminFilterOperator>) -> Self {
         self.operators = operators;
         self
@@ -216,6 +758,7 @@ impl<T: EntityTrait> From<ActixAdminModelFilter<T>> for ActixAdminViewModelFilte
         ActixAdminViewModelFilter {
             name: filter.name,
             value: None,
+            selected: Vec::new(),
             values: None,
             filter_type: Some(filter.filter_type),
             foreign_key: None,
@@ -233,6 +776,14 @@ pub struct ActixAdminModel {
     pub errors: HashMap<String, String>,
     pub custom_errors: HashMap<String, String>,
     pub display_name: Option<String>,
+    /// Display values of the view model's computed columns by name.
+    pub computed_values: HashMap<String, String>,
+    /// Output of the fields' custom Tera partials by field name, see
+    /// [`crate::routes::render_field_templates`].
+    pub rendered_fields: HashMap<String, String>,
+    /// Set by [`Self::hash_passwords`]: the values of the password fields
+    /// are hashes, which their validation attributes no longer apply to.
+    pub passwords_hashed: bool,
 }
 
 impl ActixAdminModel {
@@ -244,6 +795,9 @@ impl ActixAdminModel {
             custom_errors: HashMap::new(),
             fk_values: HashMap::new(),
             display_name: None,
+            computed_values: HashMap::new(),
+            rendered_fields: HashMap::new(),
+            passwords_hashed: false,
         }
     }
 
@@ -284,33 +838,7 @@ impl ActixAdminModel {
                     continue;
                 }
 
-                let mut filename = sanitize_upload_filename(raw_filename);
-
-                let base = PathBuf::from(file_upload_folder);
-                let mut file_path = base.join(&filename);
-
-                // Avoid overwriting existing files by prefixing a timestamp.
-                if file_path.exists() {
-                    let ts = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .map(|d| d.as_millis())
-                        .unwrap_or(0);
-                    filename = format!("{ts}_{filename}");
-                    file_path = base.join(&filename);
-                }
-
-                // Defense in depth: reject any joined path that escapes the base.
-                let canonical_base = base.canonicalize().unwrap_or_else(|_| base.clone());
-                let parent = file_path.parent().unwrap_or(&base);
-                let canonical_parent = parent
-                    .canonicalize()
-                    .unwrap_or_else(|_| parent.to_path_buf());
-                if !canonical_parent.starts_with(&canonical_base) {
-                    return Err(ActixAdminError::new(
-                        ActixAdminErrorType::UploadError,
-                        "Uploaded filename resolves outside the upload directory",
-                    ));
-                }
+                let (filename, file_path) = unique_upload_path(file_upload_folder, raw_filename)?;
 
                 let mut f = File::create(&file_path)?;
                 f.write_all(&binary_data)?;
@@ -339,6 +867,27 @@ impl ActixAdminModel {
         })
     }
 
+    /// Parse a fractional number (`Decimal`, `f32`, `f64`), accepting
+    /// thousands separators and a decimal comma; see [`normalize_number`].
+    pub fn get_number<T: std::str::FromStr>(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool,
+    ) -> Result<Option<T>, String> {
+        if let Some(val) = self.values.get(key) {
+            if !val.is_empty() && normalize_number(val).is_none() {
+                return Err(format!(
+                    "Ambiguous number `{}`: leave out the `,` or use a `.` as decimal separator",
+                    val.trim()
+                ));
+            }
+        }
+        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| {
+            normalize_number(val).unwrap_or_default().parse::<T>()
+        })
+    }
+
     pub fn get_datetime(
         &self,
         key: &str,
@@ -361,6 +910,128 @@ impl ActixAdminModel {
         })
     }
 
+    /// Parse a time of day as submitted by `<input type="time">`, with or
+    /// without seconds.
+    pub fn get_time(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool,
+    ) -> Result<Option<NaiveTime>, String> {
+        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| {
+            parse_time(val)
+        })
+    }
+
+    /// Parse a JSON document, reporting where it fails to parse.
+    pub fn get_json(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool,
+    ) -> Result<Option<serde_json::Value>, String> {
+        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| {
+            serde_json::from_str(val)
+        })
+        .map_err(|err| {
+            match self
+                .values
+                .get(key)
+                .map(|val| serde_json::from_str::<serde_json::Value>(val))
+            {
+                Some(Err(parse_err)) if !err.starts_with("Cannot") => {
+                    format!("Invalid JSON: {parse_err}")
+                }
+                _ => err,
+            }
+        })
+    }
+
+    /// A password entered into a `password` field, `None` when left empty.
+    /// Only a new record of a non-optional field requires one, an existing
+    /// record keeps its stored password. When the form submits the
+    /// `<key>_confirmation` input, both entries must match.
+    pub fn get_password(&self, key: &str, is_option: bool) -> Result<Option<String>, String> {
+        let is_allowed_to_be_empty = is_option || self.primary_key.is_some();
+        let password = self.get_value_by_closure(key, true, is_allowed_to_be_empty, |val| {
+            Ok::<_, ()>(val.to_string())
+        })?;
+        match (&password, self.values.get(&format!("{key}_confirmation"))) {
+            (Some(password), Some(confirmation)) if password != confirmation => {
+                Err("Passwords do not match".to_string())
+            }
+            _ => Ok(password),
+        }
+    }
+
+    /// Replace the entered value of every password field by its hash and drop
+    /// its confirmation. Empty values stay empty, so that an edit leaves the
+    /// stored password unchanged.
+    pub fn hash_passwords(
+        &mut self,
+        fields: &[ActixAdminViewModelField],
+        hash_password: fn(&str) -> Result<String, ActixAdminError>,
+    ) -> Result<(), ActixAdminError> {
+        for field in fields
+            .iter()
+            .filter(|field| field.field_type == ActixAdminViewModelFieldType::Password)
+        {
+            self.values
+                .remove(&format!("{}_confirmation", field.field_name));
+            if let Some(password) = self
+                .values
+                .get_mut(&field.field_name)
+                .filter(|password| !password.is_empty())
+            {
+                *password = hash_password(password)?;
+            }
+        }
+        self.passwords_hashed = true;
+        Ok(())
+    }
+
+    /// Drop the values of the fields whose `visible_when` rule does not hold,
+    /// so that they are neither validated nor saved, and return their names.
+    /// Rules are evaluated in field order against the values left so far, so
+    /// a rule on a hidden field does not hold.
+    pub fn hide_fields(&mut self, fields: &[ActixAdminViewModelField]) -> Vec<String> {
+        let mut hidden = Vec::new();
+        for field in fields {
+            if let Some(condition) = &field.visible_when {
+                if !condition.is_met(&self.values) {
+                    self.values.remove(&field.field_name);
+                    hidden.push(field.field_name.clone());
+                }
+            }
+        }
+        hidden
+    }
+
+    /// An error for every field left empty while its `required_when` rule
+    /// holds, skipping the `hidden` ones returned by [`Self::hide_fields`].
+    pub fn required_when_errors(
+        &self,
+        fields: &[ActixAdminViewModelField],
+        hidden: &[String],
+    ) -> HashMap<String, String> {
+        fields
+            .iter()
+            .filter(|field| !hidden.contains(&field.field_name))
+            .filter(|field| {
+                field
+                    .required_when
+                    .as_ref()
+                    .is_some_and(|condition| condition.is_met(&self.values))
+            })
+            .filter(|field| {
+                self.values
+                    .get(&field.field_name)
+                    .is_none_or(|value| value.trim().is_empty())
+            })
+            .map(|field| (field.field_name.clone(), "Cannot be empty".to_string()))
+            .collect()
+    }
+
     pub fn get_bool(
         &self,
         key: &str,
@@ -375,12 +1046,25 @@ impl ActixAdminModel {
         Ok(val.unwrap_or(Some(false)))
     }
 
-    fn get_value_by_closure<T: std::str::FromStr>(
+    /// Parse the option of an enum select list, see
+    /// [`ActixAdminEnumSelectListTrait`].
+    pub fn get_select_value<T: ActixAdminEnumSelectListTrait>(
         &self,
         key: &str,
         is_option_or_string: bool,
         is_allowed_to_be_empty: bool,
-        f: impl Fn(&String) -> Result<T, <T as std::str::FromStr>::Err>,
+    ) -> Result<Option<T>, String> {
+        self.get_value_by_closure(key, is_option_or_string, is_allowed_to_be_empty, |val| {
+            T::from_select_value(val).ok_or(())
+        })
+    }
+
+    fn get_value_by_closure<T, E>(
+        &self,
+        key: &str,
+        is_option_or_string: bool,
+        is_allowed_to_be_empty: bool,
+        f: impl Fn(&String) -> Result<T, E>,
     ) -> Result<Option<T>, String> {
         match self.values.get(key) {
             Some(val) => {
@@ -495,6 +1179,54 @@ mod tests {
         assert!(r.is_err());
     }
 
+    // ---- get_number / get_time ----
+
+    #[test]
+    fn normalize_number_handles_locale_formats() {
+        let normalized = |value| normalize_number(value).unwrap();
+        assert_eq!(normalized("1234.5"), "1234.5");
+        assert_eq!(normalized(" 1,5 "), "1.5");
+        assert_eq!(normalized("1.234,56"), "1234.56");
+        assert_eq!(normalized("1,234.56"), "1234.56");
+        assert_eq!(normalized("1 234 567"), "1234567");
+        assert_eq!(normalized("1.234.567"), "1234567");
+        assert_eq!(normalized("1'234.5"), "1234.5");
+        assert_eq!(normalized("-0,25"), "-0.25");
+        assert_eq!(normalized("1,2345"), "1.2345");
+        // A thousand in en-US, 1.234 elsewhere.
+        assert_eq!(normalize_number("1,234"), None);
+        assert_eq!(normalize_number("-12,500"), None);
+    }
+
+    #[test]
+    fn get_number_parses_decimal_comma() {
+        let m = model_with("n", "1.234,5");
+        assert_eq!(m.get_number::<f64>("n", false, true).unwrap(), Some(1234.5));
+        let m = model_with("n", "abc");
+        assert!(m.get_number::<f64>("n", false, true).is_err());
+        let m = model_with("n", "1,234");
+        assert_eq!(
+            m.get_number::<f64>("n", false, true).unwrap_err(),
+            "Ambiguous number `1,234`: leave out the `,` or use a `.` as decimal separator"
+        );
+    }
+
+    #[test]
+    fn get_time_accepts_optional_seconds() {
+        let m = model_with("t", "14:30");
+        assert_eq!(
+            m.get_time("t", true, true).unwrap(),
+            NaiveTime::from_hms_opt(14, 30, 0)
+        );
+        let m = model_with("t", "14:30:15");
+        assert_eq!(
+            m.get_time("t", true, true).unwrap(),
+            NaiveTime::from_hms_opt(14, 30, 15)
+        );
+        let m = model_with("t", "25:00");
+        assert!(m.get_time("t", true, true).is_err());
+    }
+
     // ---- get_bool ----
 
     #[test]
@@ -545,6 +1277,31 @@ mod tests {
         );
     }
 
+    // ---- get_password ----
+
+    #[test]
+    fn get_password_is_required_only_on_create() {
+        let m = model_with("p", "");
+        assert_eq!(m.get_password("p", false).unwrap_err(), "Cannot be empty");
+        assert_eq!(m.get_password("p", true).unwrap(), None);
+
+        let mut m = model_with("p", "");
+        m.primary_key = Some("1".to_string());
+        assert_eq!(m.get_password("p", false).unwrap(), None);
+    }
+
+    #[test]
+    fn get_password_checks_the_confirmation() {
+        let mut m = model_with("p", "secret");
+        assert_eq!(m.get_password("p", false).unwrap().unwrap(), "secret");
+        m.values
+            .insert("p_confirmation".to_string(), "Secret".to_string());
+        assert_eq!(
+            m.get_password("p", false).unwrap_err(),
+            "Passwords do not match"
+        );
+    }
+
     // ---- has_errors ----
 
     #[test]
@@ -2,7 +2,7 @@
 //!
 //! Bulk actions are registered on the [`ActixAdminBuilder`] via
 //! [`add_bulk_action_for_entity`]; the handler is a plain closure that
-//! receives the current DB connection, the list of selected ids and the
+//! receives a transaction on the DB, the list of selected ids and the
 //! tenant reference (if any), and returns a human-readable message shown
 //! as a flash notification on the redirected list page.
 //!
@@ -18,11 +18,12 @@
 
 use actix_session::Session;
 use actix_web::{http::header, web, Error, HttpRequest, HttpResponse};
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
 
 use crate::admin_prelude;
 use crate::prelude::*;
 
+use super::helpers::finish_transaction;
 use super::RoutePrelude;
 
 /// Dispatch trait implemented per entity to route named bulk actions to
@@ -33,7 +34,7 @@ use super::RoutePrelude;
 /// impl actix_admin::routes::ActixAdminBulkActionDispatch for post::Entity {
 ///     async fn run_bulk_action(
 ///         name: &str,
-///         db: &sea_orm::DatabaseConnection,
+///         db: &sea_orm::DatabaseTransaction,
 ///         ids: Vec<Self::Id>,
 ///         tenant_ref: Option<i32>,
 ///     ) -> Result<Option<String>, ActixAdminError> {
@@ -46,11 +47,12 @@ use super::RoutePrelude;
 /// ```
 #[async_trait::async_trait(?Send)]
 pub trait ActixAdminBulkActionDispatch: ActixAdminViewModelTrait {
-    /// Execute the bulk action named `name` against `ids`. Returning
-    /// `Ok(None)` means "no such action registered for this entity".
+    /// Execute the bulk action named `name` against `ids`, in a transaction
+    /// that an error rolls back. Returning `Ok(None)` means "no such action
+    /// registered for this entity".
     async fn run_bulk_action(
         _name: &str,
-        _db: &DatabaseConnection,
+        _db: &DatabaseTransaction,
         _ids: Vec<Self::Id>,
         _tenant_ref: Option<i32>,
     ) -> Result<Option<String>, ActixAdminError> {
@@ -86,7 +88,12 @@ pub async fn bulk_action<E: ActixAdminBulkActionDispatch + 'static>(
         .collect();
 
     let db = db.get_ref();
-    let result = E::run_bulk_action(&action_name, db, ids, ctx.tenant_ref).await;
+    let result = async {
+        let txn = db.begin().await?;
+        let res = E::run_bulk_action(&action_name, &txn, ids, ctx.tenant_ref).await;
+        finish_transaction(txn, res).await
+    }
+    .await;
 
     match result {
         Ok(Some(_msg)) => {
@@ -16,14 +16,18 @@ pub async fn create_get<E: ActixAdminViewModelTrait>(
     let actix_admin = data.get_ref();
     let ctx = admin_prelude!(&session, &req, actix_admin, RoutePrelude::create(), E);
 
+    let db = db.get_ref();
+    let mut model = ctx.view_model.new_model(&session, req.query_string());
+    E::load_fk_values(&mut model, db).await;
+
     render_create_or_edit_form::<E>(
         &session,
         req,
         actix_admin,
         ctx.view_model,
-        db.get_ref(),
+        db,
         ctx.entity_name,
-        &ActixAdminModel::create_empty(),
+        &model,
         ctx.tenant_ref,
         Vec::new(),
         false,
@@ -78,3 +82,54 @@ pub async fn edit_get<E: ActixAdminViewModelTrait>(
     )
     .await
 }
+
+pub async fn duplicate<E: ActixAdminViewModelTrait>(
+    session: Session,
+    req: HttpRequest,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+    id: web::Path<E::Id>,
+) -> Result<HttpResponse, Error> {
+    let actix_admin = data.get_ref();
+    let ctx = admin_prelude!(&session, &req, actix_admin, RoutePrelude::create(), E);
+
+    let db = db.get_ref();
+    let model_result = E::get_entity(db, id.into_inner(), ctx.tenant_ref).await;
+
+    let (model, notifications, status) = match model_result {
+        Ok(source) => {
+            let mut model = ctx.view_model.duplicate_model(&source);
+            E::load_fk_values(&mut model, db).await;
+            (model, Vec::new(), StatusCode::OK)
+        }
+        Err(e) if e.ty == crate::ActixAdminErrorType::EntityDoesNotExistError => {
+            let body = actix_admin
+                .tera
+                .render("not_found.html", &tera::Context::new())
+                .unwrap_or_else(|_| String::from("Not Found"));
+            return Ok(HttpResponse::NotFound()
+                .content_type("text/html")
+                .body(body));
+        }
+        Err(e) => (
+            ActixAdminModel::create_empty(),
+            vec![crate::ActixAdminNotification::from(e)],
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ),
+    };
+
+    render_create_or_edit_form::<E>(
+        &session,
+        req,
+        actix_admin,
+        ctx.view_model,
+        db,
+        ctx.entity_name,
+        &model,
+        ctx.tenant_ref,
+        notifications,
+        false,
+        status,
+    )
+    .await
+}
@@ -1,6 +1,10 @@
-use super::helpers::{add_default_context_with_session, SearchParams};
-use super::{render_create_or_edit_form, AdminAction, Params, RoutePrelude};
+use super::helpers::{add_default_context_with_session, finish_transaction, SearchParams};
+use super::{
+    render_create_or_edit_form, render_field_templates, AdminAction, FieldTemplate, Params,
+    RouteCtx, RoutePrelude,
+};
 use crate::admin_prelude;
+use crate::model::copy_upload;
 use crate::ActixAdminError;
 use crate::ActixAdminNotification;
 use crate::{prelude::*, ActixAdminErrorType};
@@ -8,7 +12,7 @@ use actix_multipart::Multipart;
 use actix_session::Session;
 use actix_web::http::{header, StatusCode};
 use actix_web::{error, web, Error, HttpRequest, HttpResponse};
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
 use std::collections::HashMap;
 use tera::Context;
 
@@ -56,6 +60,37 @@ pub async fn edit_post<E: ActixAdminViewModelTrait>(
     create_or_edit_post::<E>(&session, req, db, model, Some(id), actix_admin).await
 }
 
+pub async fn duplicate_post<E: ActixAdminViewModelTrait>(
+    session: Session,
+    req: HttpRequest,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+    payload: Multipart,
+    source_id: web::Path<E::Id>,
+) -> Result<HttpResponse, Error> {
+    let actix_admin = data.get_ref();
+    let model = ActixAdminModel::create_from_payload(
+        None,
+        payload,
+        &format!(
+            "{}/{}",
+            actix_admin.configuration.file_upload_directory,
+            E::get_entity_name()
+        ),
+    )
+    .await;
+    save_model::<E>(
+        &session,
+        req,
+        db,
+        model,
+        None,
+        Some(source_id.into_inner()),
+        actix_admin,
+    )
+    .await
+}
+
 pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
     session: &Session,
     req: HttpRequest,
@@ -63,6 +98,21 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
     model_res: Result<ActixAdminModel, ActixAdminError>,
     id: Option<E::Id>,
     actix_admin: &ActixAdmin,
+) -> Result<HttpResponse, Error> {
+    save_model::<E>(session, req, db, model_res, id, None, actix_admin).await
+}
+
+/// Create or edit the entity. A create with `duplicate_of` is a duplicate of
+/// that entity, which takes over its uploaded files when the view model
+/// copies them and no new file is uploaded.
+async fn save_model<E: ActixAdminViewModelTrait>(
+    session: &Session,
+    req: HttpRequest,
+    db: web::Data<DatabaseConnection>,
+    model_res: Result<ActixAdminModel, ActixAdminError>,
+    id: Option<E::Id>,
+    duplicate_of: Option<E::Id>,
+    actix_admin: &ActixAdmin,
 ) -> Result<HttpResponse, Error> {
     let action = if id.is_some() {
         AdminAction::Edit
@@ -99,7 +149,52 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
             .into());
         }
     };
-    let _ = E::validate_entity(&mut model, db).await;
+    // Tells an edit, which keeps an empty password, apart from a create.
+    if let Some(id) = &id {
+        model.primary_key.get_or_insert_with(|| id.to_string());
+    }
+    // Filled in with the source's files before validation, so that required
+    // file fields pass, and replaced by copies once the model is valid.
+    let mut copied_files = Vec::new();
+    if let Some(source_id) = duplicate_of.filter(|_| ctx.view_model.duplicate_copies_files) {
+        let source = match E::get_entity(db, source_id, ctx.tenant_ref).await {
+            Ok(source) => source,
+            Err(e) if e.ty == ActixAdminErrorType::EntityDoesNotExistError => {
+                let body = actix_admin
+                    .tera
+                    .render("not_found.html", &Context::new())
+                    .unwrap_or_else(|_| String::from("Not Found"));
+                return Ok(HttpResponse::NotFound()
+                    .content_type("text/html")
+                    .body(body));
+            }
+            Err(e) => return Err(error::ErrorInternalServerError(e)),
+        };
+        for field in ctx.view_model.fields.iter().filter(|field| {
+            matches!(
+                field.field_type,
+                ActixAdminViewModelFieldType::FileUpload | ActixAdminViewModelFieldType::Image
+            )
+        }) {
+            let uploaded = model
+                .values
+                .get(&field.field_name)
+                .is_some_and(|value| !value.is_empty());
+            if let Some(filename) = source.values.get(&field.field_name) {
+                if !uploaded && !filename.is_empty() {
+                    model
+                        .values
+                        .insert(field.field_name.clone(), filename.clone());
+                    copied_files.push(field.field_name.clone());
+                }
+            }
+        }
+    }
+    let operation = match &id {
+        Some(id) => ActixAdminOperation::Edit(id.to_string()),
+        None => ActixAdminOperation::Create,
+    };
+    E::validate_entity(&mut model, db, session, &operation).await;
 
     if model.has_errors() {
         let notif = vec![ActixAdminNotification::from(ActixAdminError {
@@ -122,17 +217,73 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
         .await;
     }
 
-    let res = match id {
-        Some(id) => E::edit_entity(db, id, model.clone(), ctx.tenant_ref).await,
-        None => E::create_entity(db, model.clone(), ctx.tenant_ref).await,
-    };
+    let file_upload_folder = format!(
+        "{}/{}",
+        actix_admin.configuration.file_upload_directory, ctx.entity_name
+    );
+    // The files uploaded with this request, and later the copies of a
+    // duplicate, which are removed again when the write fails.
+    let mut new_files: Vec<String> = ctx
+        .view_model
+        .fields
+        .iter()
+        .filter(|field| {
+            matches!(
+                field.field_type,
+                ActixAdminViewModelFieldType::FileUpload | ActixAdminViewModelFieldType::Image
+            ) && !copied_files.contains(&field.field_name)
+        })
+        .filter_map(|field| model.values.get(&field.field_name))
+        .filter(|filename| !filename.is_empty())
+        .cloned()
+        .collect();
+    let res = async {
+        let txn = db.begin().await?;
+        let res = write_model::<E>(
+            session,
+            actix_admin,
+            &txn,
+            &ctx,
+            &mut model,
+            id,
+            &file_upload_folder,
+            &copied_files,
+            &mut new_files,
+        )
+        .await;
+        finish_transaction(txn, res).await
+    }
+    .await;
+    if res.is_err() {
+        for filename in &new_files {
+            let file_path = format!(
+                "{}/{}",
+                file_upload_folder,
+                crate::model::sanitize_upload_filename(filename)
+            );
+            if let Err(e) = std::fs::remove_file(&file_path) {
+                log::warn!("failed to remove uploaded file {file_path}: {e}");
+            }
+        }
+    }
 
     match res {
-        Ok(model) => {
+        Ok(mut model) => {
             let params = Params::from_query(req.query_string());
             let search_params = SearchParams::from_params(&params, ctx.view_model);
 
             if ctx.view_model.inline_edit {
+                ctx.view_model
+                    .compute_columns(db, std::slice::from_mut(&mut model))
+                    .await
+                    .map_err(error::ErrorInternalServerError)?;
+                render_field_templates(
+                    actix_admin,
+                    ctx.view_model,
+                    std::slice::from_mut(&mut model),
+                    FieldTemplate::List,
+                )
+                .map_err(error::ErrorInternalServerError)?;
                 let mut tctx = Context::new();
                 tctx.insert("entity", &model);
                 super::helpers::add_auth_context(session, actix_admin, &mut tctx);
@@ -184,6 +335,60 @@ pub async fn create_or_edit_post<E: ActixAdminViewModelTrait>(
     }
 }
 
+/// Create or edit the valid `model` between its hooks, all in `txn`. Copies
+/// the files a duplicate takes over, adding the copies to `new_files`, and
+/// hashes the passwords once the `before_*` hook has accepted the model.
+#[allow(clippy::too_many_arguments)]
+async fn write_model<E: ActixAdminViewModelTrait>(
+    session: &Session,
+    actix_admin: &ActixAdmin,
+    txn: &DatabaseTransaction,
+    ctx: &RouteCtx<'_>,
+    model: &mut ActixAdminModel,
+    id: Option<E::Id>,
+    file_upload_folder: &str,
+    copied_files: &[String],
+    new_files: &mut Vec<String>,
+) -> Result<ActixAdminModel, ActixAdminError> {
+    let old = match id {
+        Some(id) => {
+            let old = E::get_entity(txn, id.clone(), ctx.tenant_ref).await?;
+            E::before_update(txn, session, &id.to_string(), &old, model).await?;
+            Some((id, old))
+        }
+        None => {
+            E::before_create(txn, session, model).await?;
+            None
+        }
+    };
+
+    for field_name in copied_files {
+        if let Some(filename) = model.values.get(field_name) {
+            let copy = copy_upload(file_upload_folder, filename)?;
+            new_files.push(copy.clone());
+            model.values.insert(field_name.clone(), copy);
+        }
+    }
+    model.hash_passwords(
+        ctx.view_model.fields,
+        actix_admin.configuration.hash_password,
+    )?;
+
+    match old {
+        Some((id, old)) => {
+            let new = E::edit_entity(txn, id.clone(), model.clone(), ctx.tenant_ref).await?;
+            E::after_update(txn, session, &id.to_string(), &old, &new).await?;
+            Ok(new)
+        }
+        None => {
+            let new = E::create_entity(txn, model.clone(), ctx.tenant_ref).await?;
+            let id = new.primary_key.clone().unwrap_or_default();
+            E::after_create(txn, session, &id, &new).await?;
+            Ok(new)
+        }
+    }
+}
+
 #[doc(hidden)]
 impl From<String> for ActixAdminModel {
     fn from(string: String) -> Self {
@@ -199,6 +404,9 @@ impl From<String> for ActixAdminModel {
             custom_errors: HashMap::new(),
             fk_values: HashMap::new(),
             display_name: None,
+            computed_values: HashMap::new(),
+            rendered_fields: HashMap::new(),
+            passwords_hashed: false,
         }
     }
 }
@@ -4,8 +4,9 @@ use crate::prelude::*;
 use actix_session::Session;
 use actix_web::http::header;
 use actix_web::{web, Error, HttpRequest, HttpResponse};
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
 
+use super::helpers::{finish_transaction, write_error_response};
 use super::query::ListQuery;
 
 /// Delete file(s) attached to file-upload fields on the given model, best-effort.
@@ -59,22 +60,41 @@ pub async fn delete<E: ActixAdminViewModelTrait>(
     let db = db.get_ref();
     let id = id.into_inner();
 
-    // Fetch first (to know upload paths) then delete.
-    let model_result = E::get_entity(db, id.clone(), ctx.tenant_ref).await;
-    let delete_result = E::delete_entity(db, id, ctx.tenant_ref).await;
+    let res = async {
+        let txn = db.begin().await?;
+        let res = delete_one::<E>(&txn, &session, id, ctx.tenant_ref).await;
+        finish_transaction(txn, res).await
+    }
+    .await;
 
-    match (model_result, delete_result) {
-        (Ok(model), Ok(_)) => {
+    match res {
+        Ok(model) => {
             delete_uploaded_files_for(actix_admin, &ctx.entity_name, ctx.view_model, &model);
             Ok(HttpResponse::Ok().finish())
         }
-        (_, Err(e)) if e.ty == crate::ActixAdminErrorType::EntityDoesNotExistError => {
+        Err(e) if e.ty == crate::ActixAdminErrorType::EntityDoesNotExistError => {
             Ok(HttpResponse::NotFound().finish())
         }
-        (_, _) => Ok(HttpResponse::InternalServerError().finish()),
+        Err(e) => write_error_response(e),
     }
 }
 
+/// Delete the entity between its hooks, all in `txn`, and return it.
+async fn delete_one<E: ActixAdminViewModelTrait>(
+    txn: &DatabaseTransaction,
+    session: &Session,
+    id: E::Id,
+    tenant_ref: Option<i32>,
+) -> Result<ActixAdminModel, ActixAdminError> {
+    // Fetch first (for the hooks and to know upload paths) then delete.
+    let model = E::get_entity(txn, id.clone(), tenant_ref).await?;
+    let id_str = id.to_string();
+    E::before_delete(txn, session, &id_str, &model).await?;
+    E::delete_entity(txn, id, tenant_ref).await?;
+    E::after_delete(txn, session, &id_str, &model).await?;
+    Ok(model)
+}
+
 pub async fn delete_many<E: ActixAdminViewModelTrait>(
     session: Session,
     req: HttpRequest,
@@ -92,7 +112,6 @@ pub async fn delete_many<E: ActixAdminViewModelTrait>(
     );
 
     let db = db.get_ref();
-    let mut errors: Vec<crate::ActixAdminError> = Vec::new();
 
     // Silently skip un-parseable ids rather than panicking on client input.
     let ids: Vec<E::Id> = form
@@ -100,38 +119,58 @@ pub async fn delete_many<E: ActixAdminViewModelTrait>(
         .filter_map(|(k, v)| (k == "ids").then(|| v.parse::<E::Id>().ok()).flatten())
         .collect();
 
-    // Pre-fetch models so we can delete their uploaded files after the DB
-    // rows go away. This is best-effort: if a fetch fails the id is skipped.
-    let mut fetched_models: Vec<ActixAdminModel> = Vec::with_capacity(ids.len());
-    for id in &ids {
-        match E::get_entity(db, id.clone(), ctx.tenant_ref).await {
-            Ok(m) => fetched_models.push(m),
-            Err(e) => errors.push(e),
-        }
+    let res = async {
+        let txn = db.begin().await?;
+        let res = delete_all::<E>(&txn, &session, &ids, ctx.tenant_ref).await;
+        finish_transaction(txn, res).await
     }
+    .await;
 
-    // Single batched DELETE ... WHERE pk IN (...).
-    match E::delete_entities(db, &ids, ctx.tenant_ref).await {
-        Ok(_) => {
-            for model in &fetched_models {
+    match res {
+        Ok(models) => {
+            for model in &models {
                 delete_uploaded_files_for(actix_admin, &ctx.entity_name, ctx.view_model, model);
             }
+            // Round-trip the pagination state that traveled in the form body
+            // back into a URL query string, using the same encoder the list
+            // route reads it with.
+            let query = ListQuery::from_form(&form, ctx.view_model);
+            Ok(HttpResponse::SeeOther()
+                .append_header((
+                    header::LOCATION,
+                    format!("list?{}", query.to_query_string()),
+                ))
+                .finish())
+        }
+        Err(e) if e.ty == crate::ActixAdminErrorType::EntityDoesNotExistError => {
+            Ok(HttpResponse::NotFound().finish())
         }
-        Err(e) => errors.push(e),
+        Err(e) => write_error_response(e),
+    }
+}
+
+/// Delete the entities between their hooks, all in `txn`, and return them.
+/// A veto of any entity or any other error deletes none of them.
+async fn delete_all<E: ActixAdminViewModelTrait>(
+    txn: &DatabaseTransaction,
+    session: &Session,
+    ids: &[E::Id],
+    tenant_ref: Option<i32>,
+) -> Result<Vec<ActixAdminModel>, ActixAdminError> {
+    // Pre-fetch models for the hooks and so we can delete their uploaded
+    // files after the DB rows go away.
+    let mut models = Vec::with_capacity(ids.len());
+    for id in ids {
+        let model = E::get_entity(txn, id.clone(), tenant_ref).await?;
+        E::before_delete(txn, session, &id.to_string(), &model).await?;
+        models.push(model);
     }
 
-    if errors.is_empty() {
-        // Round-trip the pagination state that traveled in the form body
-        // back into a URL query string, using the same encoder the list
-        // route reads it with.
-        let query = ListQuery::from_form(&form, ctx.view_model);
-        Ok(HttpResponse::SeeOther()
-            .append_header((
-                header::LOCATION,
-                format!("list?{}", query.to_query_string()),
-            ))
-            .finish())
-    } else {
-        Ok(HttpResponse::InternalServerError().finish())
+    // Single batched DELETE ... WHERE pk IN (...).
+    E::delete_entities(txn, ids, tenant_ref).await?;
+
+    for (id, model) in ids.iter().zip(&models) {
+        E::after_delete(txn, session, &id.to_string(), model).await?;
     }
+    Ok(models)
 }
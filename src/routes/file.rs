@@ -2,8 +2,9 @@ use crate::admin_prelude;
 use crate::prelude::*;
 use actix_session::Session;
 use actix_web::{error, web, Error, HttpRequest, HttpResponse};
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
 
+use super::helpers::{finish_transaction, write_error_response};
 use super::{AdminAction, RoutePrelude};
 
 /// Returns the field descriptor if `column_name` refers to a `FileUpload`
@@ -36,7 +37,7 @@ pub async fn download<E: ActixAdminViewModelTrait>(
     params: web::Path<(E::Id, String)>,
 ) -> Result<HttpResponse, Error> {
     let actix_admin = &data.into_inner();
-    let db = &db.into_inner();
+    let db = db.get_ref();
     let ctx = admin_prelude!(&session, &req, actix_admin, RoutePrelude::view(), E);
 
     let (id, column_name) = params.into_inner();
@@ -89,15 +90,23 @@ pub async fn delete_file<E: ActixAdminViewModelTrait>(
     let (id, column_name) = params.into_inner();
     let view_model_field = file_upload_field(ctx.view_model, &column_name)?;
 
-    let mut model = match E::get_entity(db.get_ref(), id.clone(), ctx.tenant_ref).await {
-        Ok(m) => m,
+    let db = db.get_ref();
+    let res = async {
+        let txn = db.begin().await?;
+        let res = clear_file::<E>(&txn, &session, id, &column_name, ctx.tenant_ref).await;
+        finish_transaction(txn, res).await
+    }
+    .await;
+    let (old, model) = match res {
+        Ok(models) => models,
         Err(e) if e.ty == crate::ActixAdminErrorType::EntityDoesNotExistError => {
             return Ok(HttpResponse::NotFound().finish());
         }
-        Err(e) => return Err(error::ErrorInternalServerError(e.to_string())),
+        Err(e) => return write_error_response(e),
     };
 
-    if let Some(file_name) = model
+    // Removed once the entity no longer refers to it.
+    if let Some(file_name) = old
         .get_value::<String>(&column_name, true, true)
         .ok()
         .flatten()
@@ -112,18 +121,35 @@ pub async fn delete_file<E: ActixAdminViewModelTrait>(
             log::warn!("failed to remove uploaded file {file_path}: {e}");
         }
     }
-    model.values.remove(&column_name);
-
-    let _edit_res = E::edit_entity(db.get_ref(), id, model.clone(), ctx.tenant_ref).await;
 
     let mut tctx = tera::Context::new();
+    super::add_auth_context(&session, actix_admin, &mut tctx);
     tctx.insert("model_field", view_model_field);
     tctx.insert("entity_name", &ctx.entity_name);
     tctx.insert("model", &model);
 
     let body = actix_admin
         .tera
-        .render("form_elements/input.html", &tctx)
+        .render("create_or_edit/input.html", &tctx)
         .map_err(error::ErrorInternalServerError)?;
     Ok(HttpResponse::Ok().content_type("text/html").body(body))
 }
+
+/// Clear the file column of the entity between its update hooks, all in
+/// `txn`, and return the entity before and after.
+async fn clear_file<E: ActixAdminViewModelTrait>(
+    txn: &DatabaseTransaction,
+    session: &Session,
+    id: E::Id,
+    column_name: &str,
+    tenant_ref: Option<i32>,
+) -> Result<(ActixAdminModel, ActixAdminModel), ActixAdminError> {
+    let old = E::get_entity(txn, id.clone(), tenant_ref).await?;
+    let mut model = old.clone();
+    model.values.remove(column_name);
+    let id_str = id.to_string();
+    E::before_update(txn, session, &id_str, &old, &mut model).await?;
+    let new = E::edit_entity(txn, id, model.clone(), tenant_ref).await?;
+    E::after_update(txn, session, &id_str, &old, &new).await?;
+    Ok((old, model))
+}
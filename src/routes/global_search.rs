@@ -0,0 +1,107 @@
+use super::helpers::{add_auth_context, render_template, user_can_perform, AdminAction};
+use crate::prelude::*;
+use crate::ActixAdminNotification;
+use actix_session::Session;
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
+use sea_orm::DatabaseConnection;
+use serde_derive::{Deserialize, Serialize};
+use tera::Context;
+
+/// Number of matches shown per entity; the rest is reachable through the
+/// entity's own list search.
+const RESULTS_PER_ENTITY: u64 = 5;
+
+#[derive(Debug, Deserialize, Default)]
+struct GlobalSearchParam {
+    #[serde(default)]
+    q: String,
+}
+
+#[derive(Serialize)]
+struct GlobalSearchGroup {
+    entity_name: String,
+    entities: Vec<ActixAdminModel>,
+    has_more: bool,
+}
+
+/// Search every registered entity with searchable fields for `q` and render
+/// the matches grouped by entity. Entities the session may not view are
+/// skipped, and each entity is scoped to the session's tenant.
+pub async fn global_search(
+    session: Session,
+    req: HttpRequest,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+) -> Result<HttpResponse, Error> {
+    let actix_admin = data.get_ref();
+    let db = db.get_ref();
+
+    let search_query: GlobalSearchParam =
+        serde_urlencoded::from_str(req.query_string()).unwrap_or_default();
+    let term = search_query.q.trim();
+
+    let tenant_ref = actix_admin
+        .configuration
+        .user_tenant_ref
+        .and_then(|f| f(&session));
+
+    // A blank term would match every row, so it renders an empty page.
+    let mut entity_names: Vec<&String> = if term.is_empty() {
+        Vec::new()
+    } else {
+        actix_admin.view_models.keys().collect()
+    };
+    entity_names.sort();
+
+    let mut groups = Vec::new();
+    let mut notifications = Vec::new();
+    for entity_name in entity_names {
+        let view_model = &actix_admin.view_models[entity_name];
+        let Some(list) = actix_admin.entity_lists.get(entity_name) else {
+            continue;
+        };
+        if !view_model.show_search
+            || !user_can_perform(&session, actix_admin, view_model, AdminAction::View)
+        {
+            continue;
+        }
+
+        let params = ActixAdminViewModelParams {
+            page: Some(1),
+            entities_per_page: Some(RESULTS_PER_ENTITY),
+            viewmodel_filter: Vec::new(),
+            filter_group: None,
+            search: term.to_string(),
+            search_exclude: Vec::new(),
+            sort_by: view_model.primary_key.clone(),
+            sort_order: SortOrder::Asc,
+            sort: Vec::new(),
+            cursor: None,
+            search_backend: view_model.search_backend.clone(),
+            search_by_relevance: view_model.search_by_relevance,
+            computed_sort_exprs: Default::default(),
+            tenant_ref,
+        };
+        match list(db, &params).await {
+            Ok((_, entities)) if entities.is_empty() => {}
+            Ok((num_pages, entities)) => groups.push(GlobalSearchGroup {
+                entity_name: entity_name.clone(),
+                entities,
+                has_more: num_pages.unwrap_or(1) > 1,
+            }),
+            Err(e) => notifications.push(ActixAdminNotification::from(e)),
+        }
+    }
+
+    let mut ctx = Context::new();
+    ctx.insert("entity_names", &actix_admin.entity_names);
+    ctx.insert("notifications", &notifications);
+    ctx.insert("global_search", term);
+    ctx.insert("global_search_encoded", &urlencoding::encode(term));
+    ctx.insert("search_results", &groups);
+    add_auth_context(&session, actix_admin, &mut ctx);
+
+    let body = render_template(&actix_admin.tera, "global_search.html", &ctx)
+        .map_err(|e| error::ErrorInternalServerError(format!("Template error: {e}")))?;
+    Ok(HttpResponse::Ok().content_type("text/html").body(body))
+}
@@ -277,6 +277,63 @@ pub fn render_template(
     }
 }
 
+/// The rendering of a field that a custom Tera partial replaces, see
+/// [`render_field_templates`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldTemplate {
+    /// `list_template`, the cell in the list and in inline-edited rows.
+    List,
+    /// `show_template`, the value on the show page.
+    Show,
+    /// `form_template`, the widget in the create / edit form.
+    Form,
+}
+
+/// Render the partial of each field that has one for `kind` into
+/// [`ActixAdminModel::rendered_fields`], which the built-in templates then
+/// output (unescaped) in place of their own rendering.
+///
+/// A partial gets the `model`, the `field` metadata, its `value`, the
+/// `fk_value` shown for a foreign key (or `none`), and the `base_path` and
+/// `entity_name` for building links.
+pub fn render_field_templates(
+    actix_admin: &ActixAdmin,
+    view_model: &ActixAdminViewModel,
+    models: &mut [ActixAdminModel],
+    kind: FieldTemplate,
+) -> Result<(), tera::Error> {
+    for field in view_model.fields {
+        let template = match kind {
+            FieldTemplate::List if field.list_hide_column => None,
+            FieldTemplate::List => field.list_template.as_deref(),
+            FieldTemplate::Show => field.show_template.as_deref(),
+            FieldTemplate::Form => field.form_template.as_deref(),
+        };
+        let Some(template) = template else {
+            continue;
+        };
+        for model in models.iter_mut() {
+            let mut ctx = Context::new();
+            ctx.insert("model", &*model);
+            ctx.insert("field", field);
+            ctx.insert(
+                "value",
+                model
+                    .values
+                    .get(&field.field_name)
+                    .map(String::as_str)
+                    .unwrap_or_default(),
+            );
+            ctx.insert("fk_value", &model.fk_values.get(&field.field_name));
+            ctx.insert("base_path", actix_admin.configuration.base_path);
+            ctx.insert("entity_name", &view_model.entity_name);
+            let html = actix_admin.tera.render(template, &ctx)?;
+            model.rendered_fields.insert(field.field_name.clone(), html);
+        }
+    }
+    Ok(())
+}
+
 /// Look up the view model for an entity name. Returns 500 rather than panicking
 /// if it is missing (should be impossible in normal operation).
 pub fn view_model_or_500<'a>(
@@ -290,6 +347,36 @@ pub fn view_model_or_500<'a>(
     })
 }
 
+/// Commit `txn` if the writes run in it succeeded, roll it back otherwise.
+pub(crate) async fn finish_transaction<T>(
+    txn: sea_orm::DatabaseTransaction,
+    res: Result<T, ActixAdminError>,
+) -> Result<T, ActixAdminError> {
+    match res {
+        Ok(value) => {
+            txn.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback) = txn.rollback().await {
+                log::warn!("failed to roll back transaction: {rollback}");
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Response to a failed write. Database errors are reported without their
+/// details, other errors, such as the veto of a hook, with their status.
+pub(crate) fn write_error_response(e: ActixAdminError) -> Result<HttpResponse, Error> {
+    match e.ty {
+        ActixAdminErrorType::DatabaseError | ActixAdminErrorType::DeleteError => {
+            Ok(HttpResponse::InternalServerError().finish())
+        }
+        _ => Err(e.into()),
+    }
+}
+
 /// Shared renderer for the create-and-edit form pages. Called from three
 /// places: create_get, edit_get, and create_or_edit_post (on validation
 /// error or DB failure). Picks the inline template when `is_inline` is set
@@ -308,6 +395,46 @@ pub async fn render_create_or_edit_form<E: ActixAdminViewModelTrait>(
     is_inline: bool,
     status: actix_web::http::StatusCode,
 ) -> Result<HttpResponse, Error> {
+    let ctx = create_or_edit_context::<E>(
+        session,
+        req,
+        actix_admin,
+        view_model,
+        db,
+        entity_name,
+        model,
+        tenant_ref,
+        notifications,
+    )
+    .await?;
+
+    let template_path = if is_inline && model.primary_key.is_some() {
+        "create_or_edit/inline.html"
+    } else {
+        "create_or_edit.html"
+    };
+    let body = render_template(&actix_admin.tera, template_path, &ctx)
+        .map_err(actix_web::error::ErrorInternalServerError)?;
+    Ok(actix_web::HttpResponse::build(status)
+        .content_type("text/html")
+        .body(body))
+}
+
+/// Template context of the create-and-edit form: the model with its
+/// rendered form templates, the select lists and the form layout. Shared by
+/// the form pages and the single-field partial of live validation.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn create_or_edit_context<E: ActixAdminViewModelTrait>(
+    session: &Session,
+    req: HttpRequest,
+    actix_admin: &ActixAdmin,
+    view_model: &ActixAdminViewModel,
+    db: &sea_orm::DatabaseConnection,
+    entity_name: String,
+    model: &ActixAdminModel,
+    tenant_ref: Option<i32>,
+    notifications: Vec<ActixAdminNotification>,
+) -> Result<Context, Error> {
     let mut ctx = Context::new();
     add_auth_context(session, actix_admin, &mut ctx);
 
@@ -320,7 +447,16 @@ pub async fn render_create_or_edit_form<E: ActixAdminViewModelTrait>(
             .await
             .map_err(actix_web::error::ErrorInternalServerError)?,
     );
-    ctx.insert("model", model);
+    let mut model = model.clone();
+    render_field_templates(
+        actix_admin,
+        view_model,
+        std::slice::from_mut(&mut model),
+        FieldTemplate::Form,
+    )
+    .map_err(actix_web::error::ErrorInternalServerError)?;
+    ctx.insert("model", &model);
+    ctx.insert("form_layout", &view_model.form_layout(&model));
 
     add_default_context_with_session(
         &mut ctx,
@@ -332,26 +468,26 @@ pub async fn render_create_or_edit_form<E: ActixAdminViewModelTrait>(
         &search_params,
         Some(session),
     );
-
-    let template_path = if is_inline && model.primary_key.is_some() {
-        "create_or_edit/inline.html"
-    } else {
-        "create_or_edit.html"
-    };
-    let body = render_template(&actix_admin.tera, template_path, &ctx)
-        .map_err(actix_web::error::ErrorInternalServerError)?;
-    Ok(actix_web::HttpResponse::build(status)
-        .content_type("text/html")
-        .body(body))
+    Ok(ctx)
 }
 
-/// Validate that `sort_by` refers to a real, non-hidden field on the view model.
+/// Validate that `sort_by` refers to a real, non-password field or a
+/// sortable computed column on the view model. Keyset cursors need a real
+/// column, so computed columns can't be sorted by with keyset pagination.
 /// Returns Ok(sort_by) or a 400 error.
 pub fn validate_sort_by(view_model: &ActixAdminViewModel, sort_by: &str) -> Result<(), Error> {
     if sort_by == view_model.primary_key {
         return Ok(());
     }
-    if view_model.fields.iter().any(|f| f.field_name == sort_by) {
+    if view_model
+        .fields
+        .iter()
+        .any(|f| f.field_name == sort_by && f.field_type != ActixAdminViewModelFieldType::Password)
+        || view_model
+            .computed_columns
+            .iter()
+            .any(|c| c.name == sort_by && c.sort_expr.is_some() && !view_model.keyset_pagination)
+    {
         Ok(())
     } else {
         Err(error::ErrorBadRequest(format!(
@@ -360,6 +496,15 @@ pub fn validate_sort_by(view_model: &ActixAdminViewModel, sort_by: &str) -> Resu
     }
 }
 
+/// Run [`validate_sort_by`] on every column of a multi-column sort.
+pub fn validate_sort_columns(
+    view_model: &ActixAdminViewModel,
+    sort: &[SortColumn],
+) -> Result<(), Error> {
+    sort.iter()
+        .try_for_each(|column| validate_sort_by(view_model, &column.field))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct SearchParams {
     pub page: u64,
@@ -367,22 +512,46 @@ pub struct SearchParams {
     pub search: String,
     pub sort_by: String,
     pub sort_order: SortOrder,
+    /// Multi-column sort list (`status,-insert_date`); empty for a plain
+    /// single-column sort.
+    #[serde(default)]
+    pub sort: String,
 }
 
 impl SearchParams {
     pub fn to_query_string(&self) -> String {
         use urlencoding::encode;
-        format!(
+        let mut qs = format!(
             "page={0}&search={1}&sort_by={2}&sort_order={3}&entities_per_page={4}",
             self.page,
             encode(&self.search),
             encode(&self.sort_by),
             self.sort_order,
             self.entities_per_page,
-        )
+        );
+        if !self.sort.is_empty() {
+            qs.push_str(&format!("&sort={}", encode(&self.sort)));
+        }
+        qs
+    }
+
+    /// All sort columns in priority order, for rendering header carets.
+    pub fn sort_columns(&self) -> Vec<SortColumn> {
+        match SortColumn::parse_list(&self.sort) {
+            columns if !columns.is_empty() => columns,
+            _ => vec![SortColumn::new(
+                self.sort_by.clone(),
+                self.sort_order.clone(),
+            )],
+        }
     }
 
     pub fn from_params(params: &Params, view_model: &ActixAdminViewModel) -> Self {
+        let sort = SortColumn::parse_list(params.sort.as_deref().unwrap_or_default());
+        let (sort_by, sort_order) = match sort.first() {
+            Some(first) if sort.len() > 1 => (Some(first.field.clone()), Some(first.order.clone())),
+            _ => (params.sort_by.clone(), params.sort_order.clone()),
+        };
         SearchParams {
             page: params.page.unwrap_or(1).max(1),
             entities_per_page: params
@@ -390,11 +559,13 @@ impl SearchParams {
                 .unwrap_or(DEFAULT_ENTITIES_PER_PAGE)
                 .max(1),
             search: params.search.clone().unwrap_or_default(),
-            sort_by: params
-                .sort_by
-                .clone()
-                .unwrap_or_else(|| view_model.primary_key.clone()),
-            sort_order: params.sort_order.clone().unwrap_or(SortOrder::Asc),
+            sort_by: sort_by.unwrap_or_else(|| view_model.primary_key.clone()),
+            sort_order: sort_order.unwrap_or(SortOrder::Asc),
+            sort: if sort.len() > 1 {
+                SortColumn::list_to_string(&sort)
+            } else {
+                String::new()
+            },
         }
     }
 
@@ -408,6 +579,11 @@ impl SearchParams {
             search: q.search.clone(),
             sort_by: q.sort_by.clone(),
             sort_order: q.sort_order.clone(),
+            sort: if q.sort.len() > 1 {
+                SortColumn::list_to_string(&q.sort)
+            } else {
+                String::new()
+            },
         }
     }
 }
@@ -472,5 +648,7 @@ pub fn add_default_context_with_session(
     ctx.insert("search", &search_params.search);
     ctx.insert("sort_by", &search_params.sort_by);
     ctx.insert("sort_order", &search_params.sort_order);
+    ctx.insert("sort", &search_params.sort);
+    ctx.insert("sort_columns", &search_params.sort_columns());
     ctx.insert("page", &search_params.page);
 }
@@ -8,7 +8,10 @@ use std::fmt;
 use tera::Context;
 
 use super::helpers::{add_default_context_with_session, SearchParams};
-use super::{add_auth_context, render_template, validate_sort_by, ListQuery, RoutePrelude};
+use super::{
+    add_auth_context, render_field_templates, render_template, validate_sort_columns,
+    FieldTemplate, ListQuery, RoutePrelude,
+};
 use crate::admin_prelude;
 use crate::ActixAdminModel;
 use crate::ActixAdminNotification;
@@ -31,6 +34,64 @@ impl fmt::Display for SortOrder {
     }
 }
 
+/// One entry of a multi-column sort. Encoded on the wire as the field name,
+/// prefixed with `-` for descending order (`sort=status,-insert_date`).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct SortColumn {
+    pub field: String,
+    pub order: SortOrder,
+}
+
+impl SortColumn {
+    pub fn new(field: impl Into<String>, order: SortOrder) -> Self {
+        Self {
+            field: field.into(),
+            order,
+        }
+    }
+
+    /// Parse a comma-separated sort list such as `status,-insert_date`.
+    /// Empty segments are skipped; never fails.
+    pub fn parse_list(s: &str) -> Vec<Self> {
+        s.split(',')
+            .filter_map(|part| part.trim().parse().ok())
+            .collect()
+    }
+
+    /// Inverse of [`Self::parse_list`].
+    pub fn list_to_string(columns: &[Self]) -> String {
+        columns
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl fmt::Display for SortColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.order {
+            SortOrder::Asc => write!(f, "{}", self.field),
+            SortOrder::Desc => write!(f, "-{}", self.field),
+        }
+    }
+}
+
+impl std::str::FromStr for SortColumn {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (field, order) = match s.strip_prefix('-') {
+            Some(rest) => (rest, SortOrder::Desc),
+            None => (s.strip_prefix('+').unwrap_or(s), SortOrder::Asc),
+        };
+        if field.is_empty() {
+            Err(())
+        } else {
+            Ok(Self::new(field, order))
+        }
+    }
+}
+
 pub fn replace_regex(view_model: &ActixAdminViewModel, models: &mut [ActixAdminModel]) {
     for field in view_model
         .fields
@@ -46,6 +107,55 @@ pub fn replace_regex(view_model: &ActixAdminViewModel, models: &mut [ActixAdminM
     }
 }
 
+/// The fields offered by the advanced filter query builder with their
+/// operators, as JSON for the template.
+fn query_builder_fields(view_model: &ActixAdminViewModel) -> String {
+    use ActixAdminFilterOperator::*;
+
+    let primary_key = serde_json::json!({
+        "name": view_model.primary_key,
+        "operators": [Equals, NotEquals, GreaterThan, GreaterEquals, LessThan, LessEquals, InList],
+    });
+    let fields = view_model
+        .fields
+        .iter()
+        .filter(|field| field.field_type != ActixAdminViewModelFieldType::Password)
+        .map(|field| {
+            let mut operators = ActixAdminViewModelFilter::from_field(field).operators;
+            if operators.is_empty() {
+                operators.push(Equals);
+            }
+            serde_json::json!({ "name": field.field_name, "operators": operators })
+        });
+    serde_json::Value::Array(std::iter::once(primary_key).chain(fields).collect()).to_string()
+}
+
+/// Drop the surplus row of a keyset page (see [`ActixAdminCursor`]) and
+/// return the `(previous, next)` cursors, i.e. the primary keys of the first
+/// and last rows when a page exists in that direction.
+fn split_keyset_page(
+    entities: &mut Vec<ActixAdminModel>,
+    cursor: &ActixAdminCursor,
+    entities_per_page: u64,
+) -> (Option<String>, Option<String>) {
+    let has_more = entities.len() as u64 > entities_per_page;
+    if has_more {
+        if cursor.is_backward() {
+            entities.remove(0);
+        } else {
+            entities.pop();
+        }
+    }
+    let (has_prev, has_next) = match cursor {
+        ActixAdminCursor::First => (false, has_more),
+        ActixAdminCursor::After(_) => (true, has_more),
+        ActixAdminCursor::Before(_) => (has_more, true),
+    };
+    let first = entities.first().and_then(|e| e.primary_key.clone());
+    let last = entities.last().and_then(|e| e.primary_key.clone());
+    (first.filter(|_| has_prev), last.filter(|_| has_next))
+}
+
 pub async fn export_csv<E: ActixAdminViewModelTrait>(
     session: Session,
     req: HttpRequest,
@@ -56,13 +166,27 @@ pub async fn export_csv<E: ActixAdminViewModelTrait>(
     let ctx = admin_prelude!(&session, &req, actix_admin, RoutePrelude::export(), E);
 
     let query = ListQuery::from_query(req.query_string(), ctx.view_model);
-    validate_sort_by(ctx.view_model, &query.sort_by)?;
+    validate_sort_columns(ctx.view_model, &query.sort)?;
+    if let Err(msg) = query
+        .structured_search
+        .as_ref()
+        .and(query.filter_group.as_ref())
+    {
+        return Err(error::ErrorBadRequest(msg.clone()));
+    }
 
-    let params = query.to_view_model_params(ctx.tenant_ref, false);
+    let mut params = query.to_view_model_params(ctx.tenant_ref, false);
+    params.search_backend = ctx.view_model.search_backend.clone();
+    params.search_by_relevance = ctx.view_model.search_by_relevance;
+    params.computed_sort_exprs = ctx.view_model.computed_sort_exprs();
 
     let entities = match E::list(&db, &params).await {
         Ok(res) => {
             let mut entities = res.1;
+            ctx.view_model
+                .compute_columns(&db, &mut entities)
+                .await
+                .map_err(error::ErrorInternalServerError)?;
             replace_regex(ctx.view_model, &mut entities);
             entities
         }
@@ -77,6 +201,12 @@ pub async fn export_csv<E: ActixAdminViewModelTrait>(
         .map(|f| f.field_name.clone())
         .collect::<Vec<_>>();
     fields.insert(0, ctx.view_model.primary_key.clone());
+    fields.extend(
+        ctx.view_model
+            .computed_columns
+            .iter()
+            .map(|c| c.name.clone()),
+    );
     writer
         .write_record(&fields)
         .map_err(error::ErrorInternalServerError)?;
@@ -97,6 +227,15 @@ pub async fn export_csv<E: ActixAdminViewModelTrait>(
                     .unwrap_or(value),
             );
         }
+        for column in &ctx.view_model.computed_columns {
+            values.push(
+                entity
+                    .computed_values
+                    .get(&column.name)
+                    .cloned()
+                    .unwrap_or_default(),
+            );
+        }
         writer
             .write_record(&values)
             .map_err(error::ErrorInternalServerError)?;
@@ -121,16 +260,49 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     let route_ctx = admin_prelude!(&session, &req, actix_admin, RoutePrelude::view(), E);
 
     let query = ListQuery::from_query(req.query_string(), route_ctx.view_model);
-    validate_sort_by(route_ctx.view_model, &query.sort_by)?;
+    validate_sort_columns(route_ctx.view_model, &query.sort)?;
+    if let Some(key) = query.cursor.as_ref().and_then(|c| c.boundary_key()) {
+        key.parse::<E::Id>()
+            .map_err(|_| error::ErrorBadRequest(format!("Invalid cursor: {key}")))?;
+    }
 
     let mut ctx = Context::new();
     add_auth_context(&session, actix_admin, &mut ctx);
 
-    let vm_params = query.to_view_model_params(route_ctx.tenant_ref, true);
+    let mut vm_params = query.to_view_model_params(route_ctx.tenant_ref, true);
+    vm_params.search_backend = route_ctx.view_model.search_backend.clone();
+    vm_params.search_by_relevance = route_ctx.view_model.search_by_relevance;
+    vm_params.computed_sort_exprs = route_ctx.view_model.computed_sort_exprs();
     let search_params = SearchParams::from_list_query(&query);
 
-    let (num_pages, mut entities) = match E::list(&db, &vm_params).await {
+    // Structured search and advanced filter mistakes are reported next to
+    // the table (with a 200, so HTMX swaps it in) rather than failing the
+    // whole page.
+    let mut search_error = query
+        .structured_search
+        .as_ref()
+        .err()
+        .or(query.filter_group.as_ref().err())
+        .cloned();
+    let listed = match search_error {
+        Some(_) => Ok((None, Vec::new())),
+        None => match E::list(&db, &vm_params).await {
+            Ok((num_pages, mut entities)) => route_ctx
+                .view_model
+                .compute_columns(&db, &mut entities)
+                .await
+                .map(|()| (num_pages, entities)),
+            Err(e) => Err(e),
+        },
+    };
+    let (num_pages, mut entities) = match listed {
         Ok(res) => res,
+        // A field value of the wrong type, e.g. `id>abc`, or an unknown
+        // advanced filter field.
+        Err(e) if e.ty == ActixAdminErrorType::BadRequest => {
+            search_error = Some(e.msg);
+            (None, Vec::new())
+        }
         Err(e) => {
             ctx.insert("entities", &Vec::<ActixAdminModel>::new());
             ctx.insert("num_pages", &0);
@@ -151,12 +323,13 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     // requested to sort by a foreign-key field. This only re-orders within
     // the current page (the primary query still orders on the FK id) but
     // gives visually correct alphabetical order in the common case of
-    // browsing without paging past `entities_per_page`.
+    // browsing without paging past `entities_per_page`. Skipped for
+    // multi-column sorts, where re-ordering would break the secondary keys.
     if let Some(field) = route_ctx
         .view_model
         .fields
         .iter()
-        .find(|f| f.field_name == query.sort_by)
+        .find(|f| query.sort.len() == 1 && f.field_name == query.sort_by)
     {
         if !field.foreign_key.is_empty() {
             let asc = matches!(query.sort_order, SortOrder::Asc);
@@ -180,7 +353,23 @@ pub async fn list<E: ActixAdminViewModelTrait>(
         }
     }
 
+    if let Some(cursor) = &query.cursor {
+        let (prev_cursor, next_cursor) =
+            split_keyset_page(&mut entities, cursor, query.entities_per_page);
+        // URL-encoded here since the templates splice them into hrefs.
+        let encode = |key: String| urlencoding::encode(&key).into_owned();
+        ctx.insert("prev_cursor", &prev_cursor.map(encode));
+        ctx.insert("next_cursor", &next_cursor.map(encode));
+    }
+
     replace_regex(route_ctx.view_model, &mut entities);
+    render_field_templates(
+        actix_admin,
+        route_ctx.view_model,
+        &mut entities,
+        FieldTemplate::List,
+    )
+    .map_err(error::ErrorInternalServerError)?;
     let num_pages = num_pages.unwrap_or(1);
     let page = query.page.min(num_pages);
     let min_show_page = page.saturating_sub(4).max(1);
@@ -198,6 +387,7 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     );
 
     ctx.insert("entities", &entities);
+    ctx.insert("search_error", &search_error);
     ctx.insert("num_pages", &num_pages);
     ctx.insert("min_show_page", &min_show_page);
     ctx.insert("max_show_page", &max_show_page);
@@ -208,10 +398,20 @@ pub async fn list<E: ActixAdminViewModelTrait>(
     for f in &query.filters {
         if let Some(entry) = viewmodel_filter.get_mut(&f.name) {
             entry.value = f.value.clone();
+            entry.selected = f.selected.clone();
             entry.operator = f.operator.clone();
         }
     }
     ctx.insert("viewmodel_filter", &viewmodel_filter);
+    let filter_group = match &query.filter_group {
+        Ok(Some(group)) => serde_json::to_string(group).unwrap_or_default(),
+        _ => String::new(),
+    };
+    ctx.insert("filter_group", &filter_group);
+    ctx.insert(
+        "query_builder_fields",
+        &query_builder_fields(route_ctx.view_model),
+    );
 
     Ok(HttpResponse::Ok().content_type("text/html").body(
         render_template(&actix_admin.tera, "list.html", &ctx)
@@ -1,14 +1,17 @@
 mod create_or_edit_get;
-pub use create_or_edit_get::{create_get, edit_get};
+pub use create_or_edit_get::{create_get, duplicate, edit_get};
 
 mod create_or_edit_post;
-pub use create_or_edit_post::{create_or_edit_post, create_post, edit_post};
+pub use create_or_edit_post::{create_or_edit_post, create_post, duplicate_post, edit_post};
+
+mod validate;
+pub use validate::{validate, validate_edit};
 
 mod index;
 pub use index::{get_admin_ctx, index, not_found};
 
 mod list;
-pub use list::{export_csv, list, SortOrder};
+pub use list::{export_csv, list, SortColumn, SortOrder};
 
 mod show;
 pub use show::show;
@@ -20,13 +23,14 @@ mod bulk_action;
 pub use bulk_action::{bulk_action, ActixAdminBulkActionDispatch};
 
 pub mod query;
-pub use query::{parse_filters_from_query, ListQuery, Params};
+pub use query::{parse_filters_from_query, ListQuery, Params, StructuredSearch};
 
 mod helpers;
 pub use helpers::{
-    add_auth_context, begin_route, forbid_if_denied, render_create_or_edit_form, render_template,
-    render_unauthorized, user_can_access_page, user_can_perform, validate_sort_by,
-    view_model_or_500, AdminAction, RouteCtx, RoutePrelude,
+    add_auth_context, begin_route, forbid_if_denied, render_create_or_edit_form,
+    render_field_templates, render_template, render_unauthorized, user_can_access_page,
+    user_can_perform, validate_sort_by, validate_sort_columns, view_model_or_500, AdminAction,
+    FieldTemplate, RouteCtx, RoutePrelude,
 };
 
 mod file;
@@ -38,4 +42,7 @@ pub use card_grid::display_card_grid;
 mod search;
 pub use search::search;
 
+mod global_search;
+pub use global_search::global_search;
+
 pub(crate) const DEFAULT_ENTITIES_PER_PAGE: u64 = 10;
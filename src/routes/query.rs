@@ -15,10 +15,13 @@
 
 use serde_derive::Deserialize;
 
+use crate::date_range::ActixAdminDateRange;
+use crate::filter_group::ActixAdminFilterGroup;
 use crate::view_model::{
-    ActixAdminFilterOperator, ActixAdminViewModelFilter, ActixAdminViewModelParams,
+    ActixAdminCursor, ActixAdminFilterOperator, ActixAdminViewModelFieldType,
+    ActixAdminViewModelFilter, ActixAdminViewModelParams,
 };
-use crate::{ActixAdminViewModel, SortOrder};
+use crate::{ActixAdminViewModel, SortColumn, SortOrder};
 
 use super::DEFAULT_ENTITIES_PER_PAGE;
 
@@ -31,6 +34,15 @@ pub struct Params {
     pub(crate) search: Option<String>,
     pub(crate) sort_by: Option<String>,
     pub(crate) sort_order: Option<SortOrder>,
+    /// Multi-column sort (`status,-insert_date`). Takes precedence over
+    /// `sort_by` / `sort_order` when present.
+    pub(crate) sort: Option<String>,
+    /// Keyset cursors: primary key of the row to continue after / before.
+    pub(crate) after: Option<String>,
+    pub(crate) before: Option<String>,
+    /// Advanced filter as JSON; see [`ActixAdminFilterGroup`].
+    #[serde(rename = "where")]
+    pub(crate) filter_group: Option<String>,
 }
 
 impl Params {
@@ -47,11 +59,18 @@ impl Params {
 /// (see [`ActixAdminFilterOperator`]'s `FromStr` impl). Operators are merged
 /// onto the corresponding value filter; a bare `__op` without a matching
 /// value is silently ignored.
+///
+/// The `filter_<name>__from`, `__to` and `__preset` inputs of a date range
+/// filter are folded into a single value, see [`ActixAdminDateRange`].
+///
+/// A repeated key, as submitted by a multi-select, yields one filter whose
+/// `selected` holds every non-empty value and whose `value` is the first.
 pub fn parse_filters_from_query(qs: &str) -> Vec<ActixAdminViewModelFilter> {
     use std::collections::HashMap;
 
-    let mut values: Vec<(String, Option<String>)> = Vec::new();
+    let mut values: Vec<(String, Vec<String>)> = Vec::new();
     let mut operators: HashMap<String, ActixAdminFilterOperator> = HashMap::new();
+    let mut ranges: Vec<(String, [String; 3])> = Vec::new();
 
     for (key, value) in form_urlencoded::parse(qs.as_bytes()) {
         let Some(rest) = key.strip_prefix("filter_") else {
@@ -63,21 +82,51 @@ pub fn parse_filters_from_query(qs: &str) -> Vec<ActixAdminViewModelFilter> {
             }
             continue;
         }
-        let v = if value.is_empty() {
-            None
-        } else {
-            Some(value.into_owned())
+        let range_part = [("__from", 0), ("__to", 1), ("__preset", 2)]
+            .into_iter()
+            .find_map(|(suffix, i)| rest.strip_suffix(suffix).map(|name| (name, i)));
+        if let Some((name, i)) = range_part {
+            let position = match ranges.iter().position(|(n, _)| n == name) {
+                Some(position) => position,
+                None => {
+                    ranges.push((name.to_string(), Default::default()));
+                    ranges.len() - 1
+                }
+            };
+            ranges[position].1[i] = value.into_owned();
+            continue;
+        }
+        let position = match values.iter().position(|(n, _)| n == rest) {
+            Some(position) => position,
+            None => {
+                values.push((rest.to_string(), Vec::new()));
+                values.len() - 1
+            }
         };
-        values.push((rest.to_string(), v));
+        if !value.is_empty() {
+            values[position].1.push(value.into_owned());
+        }
+    }
+    for (name, [from, to, preset]) in ranges {
+        let v = ActixAdminDateRange::encode(&from, &to, &preset);
+        match values.iter_mut().find(|(n, _)| *n == name) {
+            Some(entry) => {
+                if let Some(v) = v {
+                    entry.1 = vec![v];
+                }
+            }
+            None => values.push((name, v.into_iter().collect())),
+        }
     }
 
     values
         .into_iter()
-        .map(|(name, value)| {
+        .map(|(name, selected)| {
             let operator = operators.remove(&name);
             ActixAdminViewModelFilter {
                 name,
-                value,
+                value: selected.first().cloned(),
+                selected,
                 values: None,
                 filter_type: None,
                 foreign_key: None,
@@ -88,16 +137,278 @@ pub fn parse_filters_from_query(qs: &str) -> Vec<ActixAdminViewModelFilter> {
         .collect()
 }
 
+/// The list search box input, split into its parts. Whitespace-separated
+/// terms of the following forms are recognised; everything else is free
+/// text for the entity's search backend:
+///
+/// * `field:value` — for a text field the value is contained in it,
+///   otherwise equal to it,
+/// * `field=value`, `field!=value`, `field>value`, `field>=value`,
+///   `field<value`, `field<=value` — explicit comparisons,
+/// * `-field:value` (any operator) — the negated comparison,
+/// * `-word` — rows matching `word` are excluded.
+///
+/// `field` is a field name, the primary key or the name of a filter from
+/// `ActixAdminModelFilterTrait::get_filter` (case-insensitive, spaces may be
+/// written as `_`). A term whose key is none of these, like `Re: meeting` or
+/// `https://example.com`, is free text. Double quotes group words, so
+/// `title:"rust web"` and `"Post with Tom Select":3` work, and a quoted term
+/// is always free text.
+#[derive(Debug, Clone, Default)]
+pub struct StructuredSearch {
+    /// Free text, passed on as the search term.
+    pub text: String,
+    /// Negated free-text words.
+    pub exclude: Vec<String>,
+    /// `field:value` terms, in the shape the filter sidebar produces.
+    pub filters: Vec<ActixAdminViewModelFilter>,
+}
+
+/// One piece of a search term: quoted text, or bare text that may carry
+/// the `-` prefix and a `field:` key.
+#[derive(Debug)]
+struct SearchSegment {
+    text: String,
+    quoted: bool,
+}
+
+const SEARCH_OPERATORS: [(&str, ActixAdminFilterOperator); 7] = [
+    (">=", ActixAdminFilterOperator::GreaterEquals),
+    ("<=", ActixAdminFilterOperator::LessEquals),
+    ("!=", ActixAdminFilterOperator::NotEquals),
+    (":", ActixAdminFilterOperator::Contains),
+    ("=", ActixAdminFilterOperator::Equals),
+    (">", ActixAdminFilterOperator::GreaterThan),
+    ("<", ActixAdminFilterOperator::LessThan),
+];
+
+impl StructuredSearch {
+    /// Parse `input` against the fields and filters of `view_model`. Fails
+    /// with a message for the user on a missing value.
+    pub fn parse(input: &str, view_model: &ActixAdminViewModel) -> Result<Self, String> {
+        let mut search = StructuredSearch::default();
+        let mut text = Vec::new();
+
+        for mut term in split_search_terms(input) {
+            let negated = match term.first_mut() {
+                Some(first)
+                    if !first.quoted && first.text.len() > 1 && first.text.starts_with('-') =>
+                {
+                    first.text.remove(0);
+                    true
+                }
+                _ => false,
+            };
+            // `Re: meeting` or `https://example.com` are text, not fields.
+            let structured =
+                split_search_key(&term).filter(|(key, _, _)| is_search_key(view_model, key));
+            let Some((key, operator, value)) = structured else {
+                let word: String = term.iter().map(|s| s.text.as_str()).collect();
+                if negated {
+                    search.exclude.push(word);
+                } else {
+                    text.push(word);
+                }
+                continue;
+            };
+            if value.is_empty() {
+                return Err(format!("Missing value for `{key}`"));
+            }
+            search
+                .filters
+                .push(search_filter(view_model, &key, operator, value, negated)?);
+        }
+
+        search.text = text.join(" ");
+        Ok(search)
+    }
+}
+
+/// Split `input` on unquoted whitespace into terms made of segments. An
+/// unterminated quote runs to the end of the input.
+fn split_search_terms(input: &str) -> Vec<Vec<SearchSegment>> {
+    let mut terms = Vec::new();
+    let mut term: Vec<SearchSegment> = Vec::new();
+    let mut bare = String::new();
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                if !bare.is_empty() {
+                    term.push(SearchSegment {
+                        text: std::mem::take(&mut bare),
+                        quoted: false,
+                    });
+                }
+                let quoted: String = chars.by_ref().take_while(|c| *c != '"').collect();
+                term.push(SearchSegment {
+                    text: quoted,
+                    quoted: true,
+                });
+            }
+            c if c.is_whitespace() => {
+                if !bare.is_empty() {
+                    term.push(SearchSegment {
+                        text: std::mem::take(&mut bare),
+                        quoted: false,
+                    });
+                }
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            }
+            c => bare.push(c),
+        }
+    }
+    if !bare.is_empty() {
+        term.push(SearchSegment {
+            text: bare,
+            quoted: false,
+        });
+    }
+    if !term.is_empty() {
+        terms.push(term);
+    }
+    terms
+}
+
+/// Split a term into `(key, operator, value)` if it has the `key<op>value`
+/// shape, with the key either an identifier or a quoted string.
+fn split_search_key(term: &[SearchSegment]) -> Option<(String, ActixAdminFilterOperator, String)> {
+    let (key, rest, remaining) = match term {
+        [first, second, remaining @ ..] if first.quoted && !second.quoted => {
+            (first.text.clone(), second.text.as_str(), remaining)
+        }
+        [first, remaining @ ..] if !first.quoted => {
+            let end = first.text.find([':', '=', '!', '<', '>'])?;
+            let key = &first.text[..end];
+            let is_identifier = key.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !is_identifier {
+                return None;
+            }
+            (key.to_string(), &first.text[end..], remaining)
+        }
+        _ => return None,
+    };
+    let (token, operator) = SEARCH_OPERATORS
+        .iter()
+        .find(|(token, _)| rest.starts_with(token))?;
+    let value = std::iter::once(&rest[token.len()..])
+        .chain(remaining.iter().map(|s| s.text.as_str()))
+        .collect();
+    Some((key, operator.clone(), value))
+}
+
+/// Whether `key` names the primary key, a field or a registered filter of
+/// `view_model`. Password fields count too, so that [`search_filter`]
+/// rejects them rather than searching `password:...` as text.
+fn is_search_key(view_model: &ActixAdminViewModel, key: &str) -> bool {
+    let normalize = |name: &str| name.to_lowercase().replace(' ', "_");
+    std::iter::once(view_model.primary_key.as_str())
+        .chain(view_model.fields.iter().map(|f| f.field_name.as_str()))
+        .any(|name| name.eq_ignore_ascii_case(key))
+        || view_model
+            .filter_names
+            .iter()
+            .any(|name| normalize(name) == normalize(key))
+}
+
+/// Resolve `key` to a registered filter or a field of `view_model` and build
+/// the filter for it.
+fn search_filter(
+    view_model: &ActixAdminViewModel,
+    key: &str,
+    operator: ActixAdminFilterOperator,
+    value: String,
+    negated: bool,
+) -> Result<ActixAdminViewModelFilter, String> {
+    use ActixAdminFilterOperator::*;
+
+    let normalize = |name: &str| name.to_lowercase().replace(' ', "_");
+    let registered = view_model
+        .filter_names
+        .iter()
+        .find(|name| normalize(name) == normalize(key));
+    // Password hashes can't be searched, or they could be guessed one
+    // character at a time.
+    let searchable = || {
+        std::iter::once(view_model.primary_key.as_str()).chain(
+            view_model
+                .fields
+                .iter()
+                .filter(|f| f.field_type != ActixAdminViewModelFieldType::Password)
+                .map(|f| f.field_name.as_str()),
+        )
+    };
+    let field = searchable().find(|name| name.eq_ignore_ascii_case(key));
+
+    let (name, operator) = match (registered, field) {
+        // `name:value` on a registered filter means whatever the filter
+        // does with a plain value, as in the sidebar.
+        (Some(name), _) if operator == Contains && negated => {
+            return Err(format!("The filter `{name}` cannot be negated"))
+        }
+        (Some(name), _) if operator == Contains => (name.clone(), None),
+        (Some(name), _) => (name.clone(), Some(operator)),
+        (None, Some(name)) => (name.to_string(), Some(operator)),
+        (None, None) => {
+            let known: Vec<&str> = searchable()
+                .chain(view_model.filter_names.iter().map(String::as_str))
+                .collect();
+            return Err(format!(
+                "Unknown search field `{key}`. Available fields: {}",
+                known.join(", ")
+            ));
+        }
+    };
+    let operator = match (operator, negated) {
+        (operator, false) => operator,
+        (Some(Equals), true) => Some(NotEquals),
+        (Some(NotEquals), true) => Some(Equals),
+        (Some(Contains), true) => Some(NotContains),
+        (Some(GreaterThan), true) => Some(LessEquals),
+        (Some(GreaterEquals), true) => Some(LessThan),
+        (Some(LessThan), true) => Some(GreaterEquals),
+        (Some(LessEquals), true) => Some(GreaterThan),
+        (operator, true) => operator,
+    };
+
+    Ok(ActixAdminViewModelFilter {
+        name,
+        selected: vec![value.clone()],
+        value: Some(value),
+        values: None,
+        filter_type: None,
+        foreign_key: None,
+        operators: Vec::new(),
+        operator,
+    })
+}
+
 /// Fully-resolved list-page query state: pagination, search, sort and
 /// filters, normalized against the entity's view model.
 #[derive(Debug, Clone)]
 pub struct ListQuery {
     pub page: u64,
     pub entities_per_page: u64,
+    /// The search box input as typed; see [`StructuredSearch`].
     pub search: String,
+    /// `search` split into free text, exclusions and `field:value` filters,
+    /// or a message for the user when it is malformed.
+    pub structured_search: Result<StructuredSearch, String>,
+    /// Primary sort column; always equal to the first entry of `sort`.
     pub sort_by: String,
     pub sort_order: SortOrder,
+    /// All sort columns in priority order. Never empty.
+    pub sort: Vec<SortColumn>,
+    /// Keyset position; `Some` only for entities with keyset pagination
+    /// enabled, in which case `page` is ignored.
+    pub cursor: Option<ActixAdminCursor>,
     pub filters: Vec<ActixAdminViewModelFilter>,
+    /// The `where` parameter, or a message for the user when it is
+    /// malformed. `None` when absent or empty.
+    pub filter_group: Result<Option<ActixAdminFilterGroup>, String>,
 }
 
 impl ListQuery {
@@ -115,6 +426,31 @@ impl ListQuery {
         filters: Vec<ActixAdminViewModelFilter>,
         view_model: &ActixAdminViewModel,
     ) -> Self {
+        let sort = match params.sort.as_deref().map(SortColumn::parse_list) {
+            Some(columns) if !columns.is_empty() => columns,
+            _ => vec![SortColumn::new(
+                params
+                    .sort_by
+                    .unwrap_or_else(|| view_model.primary_key.clone()),
+                params.sort_order.unwrap_or(SortOrder::Asc),
+            )],
+        };
+        let cursor = view_model.keyset_pagination.then(|| {
+            match (
+                params.after.filter(|k| !k.is_empty()),
+                params.before.filter(|k| !k.is_empty()),
+            ) {
+                (Some(after), _) => ActixAdminCursor::After(after),
+                (None, Some(before)) => ActixAdminCursor::Before(before),
+                (None, None) => ActixAdminCursor::First,
+            }
+        });
+        let search = params.search.unwrap_or_default();
+        let filter_group = match params.filter_group.as_deref().map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(input) => ActixAdminFilterGroup::parse(input)
+                .map(|group| Some(group).filter(|group| !group.is_empty())),
+        };
         ListQuery {
             // Clamp to >= 1: a user-supplied `page=0` would otherwise
             // underflow `p - 1` in the paginator, and `entities_per_page=0`
@@ -124,12 +460,14 @@ impl ListQuery {
                 .entities_per_page
                 .unwrap_or(DEFAULT_ENTITIES_PER_PAGE)
                 .max(1),
-            search: params.search.unwrap_or_default(),
-            sort_by: params
-                .sort_by
-                .unwrap_or_else(|| view_model.primary_key.clone()),
-            sort_order: params.sort_order.unwrap_or(SortOrder::Asc),
+            structured_search: StructuredSearch::parse(search.as_str(), view_model),
+            search,
+            sort_by: sort[0].field.clone(),
+            sort_order: sort[0].order.clone(),
+            sort,
+            cursor,
             filters,
+            filter_group,
         }
     }
 
@@ -144,6 +482,10 @@ impl ListQuery {
                 "entities_per_page" => params.entities_per_page = v.parse().ok(),
                 "search" => params.search = Some(v.clone()),
                 "sort_by" => params.sort_by = Some(v.clone()),
+                "sort" => params.sort = Some(v.clone()),
+                "after" => params.after = Some(v.clone()),
+                "before" => params.before = Some(v.clone()),
+                "where" => params.filter_group = Some(v.clone()),
                 "sort_order" => {
                     params.sort_order = match v.as_str() {
                         "Asc" => Some(SortOrder::Asc),
@@ -158,26 +500,51 @@ impl ListQuery {
     }
 
     /// Serialize back into a URL querystring (without a leading `?`).
-    /// Uses `serde_urlencoded` so encoding matches how we parse.
+    /// Uses `serde_urlencoded` so encoding matches how we parse. The `sort`
+    /// key is only emitted for multi-column sorts, `after` / `before` only
+    /// for a keyset cursor past the first page, `where` only for a valid,
+    /// non-empty advanced filter.
     pub fn to_query_string(&self) -> String {
-        let pairs: Vec<(&str, String)> = vec![
+        let mut pairs: Vec<(&str, String)> = vec![
             ("page", self.page.to_string()),
             ("entities_per_page", self.entities_per_page.to_string()),
             ("search", self.search.clone()),
             ("sort_by", self.sort_by.clone()),
             ("sort_order", self.sort_order.to_string()),
         ];
+        if self.sort.len() > 1 {
+            pairs.push(("sort", SortColumn::list_to_string(&self.sort)));
+        }
+        match &self.cursor {
+            Some(ActixAdminCursor::After(key)) => pairs.push(("after", key.clone())),
+            Some(ActixAdminCursor::Before(key)) => pairs.push(("before", key.clone())),
+            _ => {}
+        }
+        if let Ok(Some(group)) = &self.filter_group {
+            pairs.push(("where", serde_json::to_string(group).unwrap_or_default()));
+        }
         serde_urlencoded::to_string(&pairs).unwrap_or_default()
     }
 
     /// Convert into the ORM-facing `ActixAdminViewModelParams`. `paginated`
     /// controls whether page/entities_per_page are forwarded; `export_csv`
-    /// passes `false` to fetch all rows.
+    /// passes `false` to fetch all rows. The search backend is left at its
+    /// default; routes copy it from the view model. The structured search
+    /// terms are appended to the filters; an invalid structured search falls
+    /// back to searching for the raw input.
     pub fn to_view_model_params(
         &self,
         tenant_ref: Option<i32>,
         paginated: bool,
     ) -> ActixAdminViewModelParams {
+        let mut viewmodel_filter = self.filters.clone();
+        let (search, search_exclude) = match &self.structured_search {
+            Ok(structured) => {
+                viewmodel_filter.extend(structured.filters.iter().cloned());
+                (structured.text.clone(), structured.exclude.clone())
+            }
+            Err(_) => (self.search.clone(), Vec::new()),
+        };
         ActixAdminViewModelParams {
             page: if paginated { Some(self.page) } else { None },
             entities_per_page: if paginated {
@@ -185,10 +552,17 @@ impl ListQuery {
             } else {
                 None
             },
-            viewmodel_filter: self.filters.clone(),
-            search: self.search.clone(),
+            viewmodel_filter,
+            filter_group: self.filter_group.clone().ok().flatten(),
+            search,
+            search_exclude,
             sort_by: self.sort_by.clone(),
             sort_order: self.sort_order.clone(),
+            sort: self.sort.clone(),
+            cursor: if paginated { self.cursor.clone() } else { None },
+            search_backend: Default::default(),
+            search_by_relevance: false,
+            computed_sort_exprs: Default::default(),
             tenant_ref,
         }
     }
@@ -239,6 +613,41 @@ mod tests {
         assert_eq!(filters[0].value.as_deref(), Some("a=b"));
     }
 
+    #[test]
+    fn filter_parser_folds_date_range_inputs() {
+        let filters = parse_filters_from_query(
+            "filter_Created__from=2024-01-01&filter_Created__to=&filter_Created__preset=\
+             &filter_Updated__from=2024-01-01&filter_Updated__preset=today\
+             &filter_Deleted__from=&filter_Deleted__to=",
+        );
+        let values: Vec<_> = filters
+            .iter()
+            .map(|f| (f.name.as_str(), f.value.as_deref()))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("Created", Some("2024-01-01..")),
+                ("Updated", Some("today")),
+                ("Deleted", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_parser_collects_repeated_keys() {
+        let filters = parse_filters_from_query(
+            "filter_tea=Green&filter_tea=&filter_tea=Black&filter_tea__op=not_equals",
+        );
+        assert_eq!(filters.len(), 1);
+        assert_eq!(filters[0].selected, vec!["Green", "Black"]);
+        assert_eq!(filters[0].value.as_deref(), Some("Green"));
+        assert_eq!(
+            filters[0].operator,
+            Some(ActixAdminFilterOperator::NotEquals)
+        );
+    }
+
     #[test]
     fn filter_parser_decodes_plus_as_space_in_key_and_value() {
         let filters = parse_filters_from_query("filter_Post+with+Tom+Select=hello+world");
@@ -254,4 +663,25 @@ mod tests {
         assert_eq!(filters[0].name, "Post with Tom Select");
         assert_eq!(filters[0].value.as_deref(), Some("a/b"));
     }
+
+    #[test]
+    fn params_from_query_parses_multi_column_sort() {
+        let p = Params::from_query("sort=status,-insert_date");
+        let sort = SortColumn::parse_list(p.sort.as_deref().unwrap_or_default());
+        assert_eq!(
+            sort,
+            vec![
+                SortColumn::new("status", SortOrder::Asc),
+                SortColumn::new("insert_date", SortOrder::Desc),
+            ]
+        );
+    }
+
+    #[test]
+    fn sort_column_list_round_trips() {
+        let sort = SortColumn::parse_list("title,-id, ,+status");
+        assert_eq!(sort.len(), 3);
+        assert_eq!(SortColumn::list_to_string(&sort), "title,-id,status");
+        assert!("-".parse::<SortColumn>().is_err());
+    }
 }
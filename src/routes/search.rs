@@ -1,4 +1,3 @@
-use super::list::replace_regex;
 use super::RoutePrelude;
 use crate::admin_prelude;
 use crate::prelude::*;
@@ -7,6 +6,11 @@ use actix_web::{error, web, Error, HttpRequest, HttpResponse};
 use sea_orm::DatabaseConnection;
 use serde_derive::{Deserialize, Serialize};
 
+/// Options returned per request when the caller passes no `limit`.
+const DEFAULT_LOOKUP_LIMIT: u64 = 20;
+/// Upper bound for a caller-supplied `limit`.
+const MAX_LOOKUP_LIMIT: u64 = 100;
+
 #[derive(Serialize)]
 struct LabelValue {
     label: String,
@@ -16,14 +20,21 @@ struct LabelValue {
 #[derive(Serialize)]
 struct SearchList {
     items: Vec<LabelValue>,
+    /// The `offset` of the next page, `None` on the last one.
+    next_offset: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Default)]
 pub struct SearchParam {
     #[serde(default)]
     q: String,
+    #[serde(default)]
+    offset: u64,
+    limit: Option<u64>,
 }
 
+/// Tom-select lookup: `?q=term&offset=0&limit=20` returns a page of
+/// `{label, value}` options plus the offset to load more from.
 pub async fn search<E: ActixAdminViewModelTrait>(
     session: Session,
     req: HttpRequest,
@@ -36,35 +47,32 @@ pub async fn search<E: ActixAdminViewModelTrait>(
 
     let search_query: SearchParam =
         serde_urlencoded::from_str(req.query_string()).unwrap_or_default();
+    let limit = search_query
+        .limit
+        .unwrap_or(DEFAULT_LOOKUP_LIMIT)
+        .clamp(1, MAX_LOOKUP_LIMIT);
 
-    let params = ActixAdminViewModelParams {
-        page: None,
-        entities_per_page: None,
-        viewmodel_filter: Vec::new(),
+    // One row past the page tells whether there is another one.
+    let params = ActixAdminLookupParams {
         search: search_query.q,
-        sort_by: ctx.view_model.primary_key.clone(),
-        sort_order: SortOrder::Asc,
+        search_backend: ctx.view_model.search_backend.clone(),
+        search_by_relevance: ctx.view_model.search_by_relevance,
+        offset: search_query.offset,
+        limit: limit + 1,
         tenant_ref: ctx.tenant_ref,
     };
 
-    // TODO: Improve by not loading all values (add a limit clause)
-    let entities = match E::list(db, &params).await {
-        Ok(res) => {
-            let mut entities = res.1;
-            replace_regex(ctx.view_model, &mut entities);
-            entities
-                .into_iter()
-                .filter_map(|e| {
-                    let value = e.primary_key?;
-                    Some(LabelValue {
-                        label: e.display_name.unwrap_or_default(),
-                        value,
-                    })
-                })
-                .collect()
-        }
+    let mut items: Vec<LabelValue> = match E::lookup(db, &params).await {
+        Ok(rows) => rows
+            .into_iter()
+            .map(|(value, label)| LabelValue { label, value })
+            .collect(),
         Err(e) => return Err(error::ErrorInternalServerError(e.to_string())),
     };
+    let next_offset = (items.len() as u64 > limit).then(|| {
+        items.truncate(limit as usize);
+        search_query.offset + limit
+    });
 
-    Ok(HttpResponse::Ok().json(SearchList { items: entities }))
+    Ok(HttpResponse::Ok().json(SearchList { items, next_offset }))
 }
@@ -9,7 +9,9 @@ use sea_orm::DatabaseConnection;
 use tera::Context;
 
 use super::Params;
-use super::{add_auth_context, render_template, RoutePrelude};
+use super::{
+    add_auth_context, render_field_templates, render_template, FieldTemplate, RoutePrelude,
+};
 
 pub async fn show<E: ActixAdminViewModelTrait>(
     session: Session,
@@ -22,7 +24,7 @@ pub async fn show<E: ActixAdminViewModelTrait>(
     let ctx_data = admin_prelude!(&session, &req, actix_admin, RoutePrelude::view(), E);
 
     let mut errors: Vec<crate::ActixAdminError> = Vec::new();
-    let model = match E::get_entity(&db, id.into_inner(), ctx_data.tenant_ref).await {
+    let mut model = match E::get_entity(db.get_ref(), id.into_inner(), ctx_data.tenant_ref).await {
         Ok(res) => res,
         Err(e) if e.ty == crate::ActixAdminErrorType::EntityDoesNotExistError => {
             // Short-circuit: don't try to render show.html with an empty model.
@@ -39,6 +41,22 @@ pub async fn show<E: ActixAdminViewModelTrait>(
             ActixAdminModel::create_empty()
         }
     };
+    if errors.is_empty() {
+        if let Err(e) = ctx_data
+            .view_model
+            .compute_columns(&db, std::slice::from_mut(&mut model))
+            .await
+        {
+            errors.push(e);
+        }
+    }
+    render_field_templates(
+        actix_admin,
+        ctx_data.view_model,
+        std::slice::from_mut(&mut model),
+        FieldTemplate::Show,
+    )
+    .map_err(error::ErrorInternalServerError)?;
 
     let mut http_response_code = match errors.first() {
         None => HttpResponse::Ok(),
@@ -69,6 +87,7 @@ pub async fn show<E: ActixAdminViewModelTrait>(
         Some(&session),
     );
     ctx.insert("model", &model);
+    ctx.insert("form_layout", &ctx_data.view_model.form_layout(&model));
 
     let body = render_template(&actix_admin.tera, "show.html", &ctx)
         .map_err(error::ErrorInternalServerError)?;
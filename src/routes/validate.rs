@@ -0,0 +1,110 @@
+use super::helpers::create_or_edit_context;
+use super::{render_template, RoutePrelude};
+use crate::admin_prelude;
+use crate::prelude::*;
+use actix_session::Session;
+use actix_web::{error, web, Error, HttpRequest, HttpResponse};
+use sea_orm::DatabaseConnection;
+use serde_derive::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ValidateParams {
+    field: String,
+}
+
+/// Live validation of a create form: `POST /validate?field=name` with the
+/// url-encoded form state returns the re-rendered field with its error.
+pub async fn validate<E: ActixAdminViewModelTrait>(
+    session: Session,
+    req: HttpRequest,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+    params: web::Query<ValidateParams>,
+    body: String,
+) -> Result<HttpResponse, Error> {
+    validate_field::<E>(
+        &session,
+        req,
+        data.get_ref(),
+        db.get_ref(),
+        None,
+        params,
+        body,
+    )
+    .await
+}
+
+/// Live validation of an edit form, see [`validate`].
+pub async fn validate_edit<E: ActixAdminViewModelTrait>(
+    session: Session,
+    req: HttpRequest,
+    data: web::Data<ActixAdmin>,
+    db: web::Data<DatabaseConnection>,
+    id: web::Path<E::Id>,
+    params: web::Query<ValidateParams>,
+    body: String,
+) -> Result<HttpResponse, Error> {
+    let id = id.into_inner().to_string();
+    validate_field::<E>(
+        &session,
+        req,
+        data.get_ref(),
+        db.get_ref(),
+        Some(id),
+        params,
+        body,
+    )
+    .await
+}
+
+/// Runs the same validation as a submit, without writing anything, and
+/// renders only `create_or_edit/field.html` of the requested field.
+async fn validate_field<E: ActixAdminViewModelTrait>(
+    session: &Session,
+    req: HttpRequest,
+    actix_admin: &ActixAdmin,
+    db: &DatabaseConnection,
+    id: Option<String>,
+    params: web::Query<ValidateParams>,
+    body: String,
+) -> Result<HttpResponse, Error> {
+    let prelude = match id {
+        Some(_) => RoutePrelude::edit(),
+        None => RoutePrelude::create(),
+    };
+    let ctx = admin_prelude!(session, &req, actix_admin, prelude, E);
+
+    let field = ctx
+        .view_model
+        .fields
+        .iter()
+        .find(|f| f.field_name == params.field && !f.is_tenant_ref)
+        .ok_or_else(|| error::ErrorBadRequest(format!("unknown field '{}'", params.field)))?;
+
+    // The form is posted url-encoded, so file inputs carry no upload.
+    let mut model = ActixAdminModel::from(body);
+    model.primary_key = id.clone();
+    let operation = match id {
+        Some(id) => ActixAdminOperation::Edit(id),
+        None => ActixAdminOperation::Create,
+    };
+    E::validate_entity(&mut model, db, session, &operation).await;
+    E::load_fk_values(&mut model, db).await;
+
+    let mut tctx = create_or_edit_context::<E>(
+        session,
+        req,
+        actix_admin,
+        ctx.view_model,
+        db,
+        ctx.entity_name,
+        &model,
+        ctx.tenant_ref,
+        Vec::new(),
+    )
+    .await?;
+    tctx.insert("model_field", field);
+    let body = render_template(&actix_admin.tera, "create_or_edit/field.html", &tctx)
+        .map_err(error::ErrorInternalServerError)?;
+    Ok(HttpResponse::Ok().content_type("text/html").body(body))
+}
@@ -0,0 +1,195 @@
+//! Search backends for the list search box and the tom-select `/search`
+//! endpoint.
+//!
+//! The backend is picked per entity via
+//! [`ActixAdminViewModel::search_backend`](crate::view_model::ActixAdminViewModel)
+//! and applied by the generated `list_model` to the entity's `searchable`
+//! columns.
+
+use crate::model::{column_contains, column_not_contains};
+use sea_orm::sea_query::{Alias, Expr, Query};
+use sea_orm::{ColumnTrait, Condition, Order};
+
+/// How a search term is matched against an entity's `searchable` columns.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ActixAdminSearchBackend {
+    /// `column LIKE '%term%'` on every searchable column, OR'd together,
+    /// with `%` and `_` in the term matched literally.
+    /// Works everywhere but cannot use indexes and does not rank results.
+    #[default]
+    Like,
+    /// PostgreSQL full-text search:
+    /// `to_tsvector(config, columns) @@ websearch_to_tsquery(config, term)`,
+    /// ranked with `ts_rank`. `config` is a text search configuration such as
+    /// `"english"` or `"simple"`. Set `tsvector_column` to match against a
+    /// stored (and GIN-indexed) `tsvector` column instead of building the
+    /// vector from the searchable columns on the fly.
+    Postgres {
+        config: String,
+        tsvector_column: Option<String>,
+    },
+    /// SQLite FTS5: matches against the external-content FTS5 virtual table
+    /// `table`, whose `rowid` must equal the entity's primary key, and ranks
+    /// with FTS5's built-in `bm25` rank. Each whitespace-separated word of the
+    /// term is matched as a quoted prefix, so user input can't produce FTS5
+    /// syntax errors.
+    SqliteFts5 { table: String },
+}
+
+impl ActixAdminSearchBackend {
+    /// Condition restricting a query to rows matching `term`. Full-text
+    /// backends match everything for a blank term.
+    pub fn condition<C: ColumnTrait>(
+        &self,
+        columns: &[C],
+        primary_key: C,
+        term: &str,
+    ) -> Condition {
+        match self {
+            ActixAdminSearchBackend::Like => {
+                columns.iter().fold(Condition::any(), |cond, column| {
+                    cond.add(column_contains(*column, term))
+                })
+            }
+            _ if term.trim().is_empty() => Condition::all(),
+            ActixAdminSearchBackend::Postgres {
+                config,
+                tsvector_column,
+            } => Condition::all().add(Expr::cust_with_exprs(
+                "$1 @@ websearch_to_tsquery($2::regconfig, $3)",
+                [
+                    postgres_tsvector(config, tsvector_column.as_deref(), columns, primary_key),
+                    Expr::val(config.as_str()),
+                    Expr::val(term),
+                ],
+            )),
+            ActixAdminSearchBackend::SqliteFts5 { table } => Condition::all().add(
+                primary_key.in_subquery(
+                    Query::select()
+                        .expr(Expr::cust("rowid"))
+                        .from(Alias::new(table))
+                        .and_where(Self::fts5_match(table, term))
+                        .to_owned(),
+                ),
+            ),
+        }
+    }
+
+    /// Condition restricting a query to rows not matching `term`, the
+    /// negation of [`Self::condition`]. For `Like`, a `NULL` column doesn't
+    /// match, rather than making the row unknown and dropping it.
+    pub fn exclude_condition<C: ColumnTrait>(
+        &self,
+        columns: &[C],
+        primary_key: C,
+        term: &str,
+    ) -> Condition {
+        match self {
+            ActixAdminSearchBackend::Like => {
+                columns.iter().fold(Condition::all(), |cond, column| {
+                    cond.add(column_not_contains(*column, term))
+                })
+            }
+            _ => self.condition(columns, primary_key, term).not(),
+        }
+    }
+
+    /// Ordering that puts the best matches for `term` first, or `None` when
+    /// the backend doesn't rank (`Like`) or the term is blank.
+    pub fn relevance<C: ColumnTrait>(
+        &self,
+        columns: &[C],
+        primary_key: C,
+        term: &str,
+    ) -> Option<(Expr, Order)> {
+        if term.trim().is_empty() {
+            return None;
+        }
+        match self {
+            ActixAdminSearchBackend::Like => None,
+            ActixAdminSearchBackend::Postgres {
+                config,
+                tsvector_column,
+            } => Some((
+                Expr::cust_with_exprs(
+                    "ts_rank($1, websearch_to_tsquery($2::regconfig, $3))",
+                    [
+                        postgres_tsvector(config, tsvector_column.as_deref(), columns, primary_key),
+                        Expr::val(config.as_str()),
+                        Expr::val(term),
+                    ],
+                ),
+                Order::Desc,
+            )),
+            // FTS5's `rank` is bm25 scaled so that better matches sort lower.
+            ActixAdminSearchBackend::SqliteFts5 { table } => Some((
+                Query::select()
+                    .expr(Expr::cust("rank"))
+                    .from(Alias::new(table))
+                    .and_where(Self::fts5_match(table, term))
+                    .and_where(Expr::cust_with_expr(
+                        "rowid = ?",
+                        Expr::col(primary_key.as_column_ref()),
+                    ))
+                    .to_owned()
+                    .into(),
+                Order::Asc,
+            )),
+        }
+    }
+
+    // Custom SQL placeholders follow the target backend: `?` for the SQLite
+    // expressions, `$n` for the Postgres ones.
+    fn fts5_match(table: &str, term: &str) -> Expr {
+        Expr::cust_with_exprs(
+            "? MATCH ?",
+            [
+                Expr::col(Alias::new(table)),
+                Expr::val(fts5_prefix_query(term)),
+            ],
+        )
+    }
+}
+
+/// The document vector for the Postgres backend: the stored column when
+/// configured, otherwise all searchable columns joined by spaces.
+fn postgres_tsvector<C: ColumnTrait>(
+    config: &str,
+    tsvector_column: Option<&str>,
+    columns: &[C],
+    primary_key: C,
+) -> Expr {
+    if let Some(column) = tsvector_column {
+        return Expr::col((primary_key.entity_name(), Alias::new(column)));
+    }
+    let placeholders = (0..columns.len())
+        .map(|i| format!("${}", i + 2))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Expr::cust_with_exprs(
+        format!("to_tsvector($1::regconfig, concat_ws(' ', {placeholders}))"),
+        std::iter::once(Expr::val(config))
+            .chain(columns.iter().map(|c| Expr::col(c.as_column_ref()))),
+    )
+}
+
+/// Turn free user input into an FTS5 query of quoted prefix terms, e.g.
+/// `rust "web` becomes `"rust"* """web"*`.
+fn fts5_prefix_query(term: &str) -> String {
+    term.split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fts5_prefix_query_quotes_every_word() {
+        assert_eq!(fts5_prefix_query("  rust  web "), r#""rust"* "web"*"#);
+        assert_eq!(fts5_prefix_query(r#"a"b -c"#), r#""a""b"* "-c"*"#);
+        assert_eq!(fts5_prefix_query(""), "");
+    }
+}
@@ -22,6 +22,9 @@ pub fn get_tera() -> Tera {
     tera.register_filter("get_icon", get_icon);
     tera.register_filter("get_regex_val", get_regex_val);
     tera.register_filter("shorten", shorten_filter);
+    tera.register_filter("json_escape", json_escape);
+    tera.register_filter("json_pretty", json_pretty);
+    tera.register_filter("json_tree", json_tree);
     // Filters that existed in tera 1 but were removed in tera 2. We
     // provide compatibility shims so the shipped templates keep working.
     tera.register_filter("date", date_filter);
@@ -47,6 +50,112 @@ fn shorten_filter(value: &Value, kwargs: Kwargs, _: &State) -> TeraResult<Value>
     }
 }
 
+/// Escape a string for use inside a double-quoted JSON string, e.g. the
+/// `hx-vals` attributes. The result is still HTML-escaped on output.
+fn json_escape(value: &Value, _: Kwargs, _: &State) -> TeraResult<Value> {
+    let input = value.as_str().unwrap_or("");
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    Ok(Value::from(escaped))
+}
+
+/// Pretty-print a JSON document for the editor; anything that does not
+/// parse (e.g. a rejected submission) is returned unchanged.
+fn json_pretty(value: &Value, _: Kwargs, _: &State) -> TeraResult<Value> {
+    let input = value.as_str().unwrap_or("");
+    match serde_json::from_str::<serde_json::Value>(input) {
+        Ok(json) => Ok(Value::from(
+            serde_json::to_string_pretty(&json).unwrap_or_else(|_| input.to_string()),
+        )),
+        Err(_) => Ok(Value::from(input)),
+    }
+}
+
+/// Render a JSON document as nested `<details>` elements for the show view.
+/// The output is escaped and meant to be emitted with `| safe`.
+fn json_tree(value: &Value, _: Kwargs, _: &State) -> TeraResult<Value> {
+    let input = value.as_str().unwrap_or("");
+    let mut html = String::from("<div class=\"aa-json-tree\">");
+    match serde_json::from_str::<serde_json::Value>(input) {
+        Ok(json) => json_tree_node(&mut html, None, &json, 0),
+        Err(_) => html.push_str(&escape_html(input)),
+    }
+    html.push_str("</div>");
+    Ok(Value::from(html))
+}
+
+fn json_tree_node(html: &mut String, key: Option<&str>, json: &serde_json::Value, depth: usize) {
+    use serde_json::Value as Json;
+
+    let label = key
+        .map(|key| format!("<span class=\"aa-json-key\">{}</span>: ", escape_html(key)))
+        .unwrap_or_default();
+    let children: Vec<(String, &Json)> = match json {
+        Json::Object(map) => map.iter().map(|(k, v)| (k.clone(), v)).collect(),
+        Json::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (i.to_string(), v))
+            .collect(),
+        scalar => {
+            let class = match scalar {
+                Json::String(_) => "aa-json-string",
+                Json::Number(_) => "aa-json-number",
+                Json::Bool(_) => "aa-json-boolean",
+                _ => "aa-json-null",
+            };
+            html.push_str(&format!(
+                "{label}<span class=\"{class}\">{}</span>",
+                escape_html(&scalar.to_string())
+            ));
+            return;
+        }
+    };
+
+    let (open, close, unit) = match json {
+        Json::Object(_) => ("{", "}", "key"),
+        _ => ("[", "]", "item"),
+    };
+    if children.is_empty() {
+        html.push_str(&format!("{label}{open}{close}"));
+        return;
+    }
+    let plural = if children.len() == 1 { "" } else { "s" };
+    html.push_str(&format!(
+        "<details{}><summary>{label}{open} <span class=\"aa-json-count\">{} {unit}{plural}</span></summary><ul>",
+        if depth < 2 { " open" } else { "" },
+        children.len()
+    ));
+    for (child_key, child) in children {
+        html.push_str("<li>");
+        json_tree_node(html, Some(&child_key), child, depth + 1);
+        html.push_str("</li>");
+    }
+    html.push_str(&format!("</ul>{close}</details>"));
+}
+
+fn escape_html(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#x27;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 fn get_html_input_class(value: &Value, _: Kwargs, _: &State) -> TeraResult<Value> {
     let field: ActixAdminViewModelField = from_value("get_html_input_class", value)?;
     let html_input_type = match field.field_type {
@@ -104,10 +213,13 @@ fn get_html_input_type(value: &Value, _: Kwargs, _: &State) -> TeraResult<Value>
 
     let html_input_type = match field.field_type {
         ActixAdminViewModelFieldType::Text => "text",
+        ActixAdminViewModelFieldType::Number => "number",
         ActixAdminViewModelFieldType::DateTime => "datetime-local",
         ActixAdminViewModelFieldType::Date => "date",
+        ActixAdminViewModelFieldType::Time => "time",
         ActixAdminViewModelFieldType::Checkbox => "checkbox",
         ActixAdminViewModelFieldType::FileUpload => "file",
+        ActixAdminViewModelFieldType::Password => "password",
         _ => "text",
     };
 
@@ -207,10 +319,26 @@ fn load_templates_into(tera: &mut Tera) {
             include_str!("templates/bulma/not_found.html"),
         ),
         ("show.html", include_str!("templates/bulma/show.html")),
+        (
+            "global_search.html",
+            include_str!("templates/bulma/global_search.html"),
+        ),
         (
             "unauthorized.html",
             include_str!("templates/bulma/unauthorized.html"),
         ),
+        (
+            "form_layout.html",
+            include_str!("templates/bulma/form_layout.html"),
+        ),
+        (
+            "create_or_edit/field.html",
+            include_str!("templates/bulma/create_or_edit/field.html"),
+        ),
+        (
+            "show/field.html",
+            include_str!("templates/bulma/show/field.html"),
+        ),
         (
             "create_or_edit/checkbox.html",
             include_str!("templates/bulma/create_or_edit/checkbox.html"),
@@ -219,6 +347,10 @@ fn load_templates_into(tera: &mut Tera) {
             "create_or_edit/input.html",
             include_str!("templates/bulma/create_or_edit/input.html"),
         ),
+        (
+            "create_or_edit/constraints.html",
+            include_str!("templates/bulma/create_or_edit/constraints.html"),
+        ),
         (
             "create_or_edit/selectlist.html",
             include_str!("templates/bulma/create_or_edit/selectlist.html"),
@@ -239,6 +371,10 @@ fn load_templates_into(tera: &mut Tera) {
             "list/filter.html",
             include_str!("templates/bulma/list/filter.html"),
         ),
+        (
+            "list/query_builder.html",
+            include_str!("templates/bulma/list/query_builder.html"),
+        ),
         (
             "card_grid.html",
             include_str!("templates/bulma/card_grid.html"),
@@ -274,10 +410,26 @@ fn load_templates_into(tera: &mut Tera) {
             include_str!("templates/bootstrapv5/not_found.html"),
         ),
         ("show.html", include_str!("templates/bootstrapv5/show.html")),
+        (
+            "global_search.html",
+            include_str!("templates/bootstrapv5/global_search.html"),
+        ),
         (
             "unauthorized.html",
             include_str!("templates/bootstrapv5/unauthorized.html"),
         ),
+        (
+            "form_layout.html",
+            include_str!("templates/bootstrapv5/form_layout.html"),
+        ),
+        (
+            "create_or_edit/field.html",
+            include_str!("templates/bootstrapv5/create_or_edit/field.html"),
+        ),
+        (
+            "show/field.html",
+            include_str!("templates/bootstrapv5/show/field.html"),
+        ),
         (
             "create_or_edit/checkbox.html",
             include_str!("templates/bootstrapv5/create_or_edit/checkbox.html"),
@@ -286,6 +438,10 @@ fn load_templates_into(tera: &mut Tera) {
             "create_or_edit/input.html",
             include_str!("templates/bootstrapv5/create_or_edit/input.html"),
         ),
+        (
+            "create_or_edit/constraints.html",
+            include_str!("templates/bootstrapv5/create_or_edit/constraints.html"),
+        ),
         (
             "create_or_edit/selectlist.html",
             include_str!("templates/bootstrapv5/create_or_edit/selectlist.html"),
@@ -306,6 +462,10 @@ fn load_templates_into(tera: &mut Tera) {
             "list/filter.html",
             include_str!("templates/bootstrapv5/list/filter.html"),
         ),
+        (
+            "list/query_builder.html",
+            include_str!("templates/bootstrapv5/list/query_builder.html"),
+        ),
         (
             "card_grid.html",
             include_str!("templates/bootstrapv5/card_grid.html"),
@@ -1,23 +1,107 @@
 use async_trait::async_trait;
+use futures_util::future::LocalBoxFuture;
 use regex::Regex;
-use sea_orm::DatabaseConnection;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
 use serde_derive::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::filter_group::ActixAdminFilterGroup;
+use crate::search_backend::ActixAdminSearchBackend;
 use crate::ActixAdminError;
-use crate::{model::ActixAdminModelFilterType, ActixAdminModel, SortOrder};
+use crate::{
+    model::{ActixAdminModelFilterType, ActixAdminModelHooksTrait, ActixAdminOperation},
+    ActixAdminModel, SortColumn, SortOrder,
+};
 use actix_session::Session;
 use std::convert::From;
 pub struct ActixAdminViewModelParams {
     pub page: Option<u64>,
     pub entities_per_page: Option<u64>,
     pub viewmodel_filter: Vec<ActixAdminViewModelFilter>,
+    /// Advanced AND / OR filter, combined with AND with everything else.
+    pub filter_group: Option<ActixAdminFilterGroup>,
     pub search: String,
+    /// Terms excluded from the results (`-draft` in the list search box):
+    /// rows the search backend matches for any of them are filtered out.
+    pub search_exclude: Vec<String>,
     pub sort_by: String,
     pub sort_order: SortOrder,
+    /// Full multi-column sort, in priority order. When empty, `sort_by` /
+    /// `sort_order` are used as the single sort column.
+    pub sort: Vec<SortColumn>,
+    /// Keyset pagination position. `None` selects classic offset pagination
+    /// (`page` + `COUNT(*)`); see [`ActixAdminCursor`].
+    pub cursor: Option<ActixAdminCursor>,
+    /// How `search` is matched against the entity's searchable columns.
+    pub search_backend: ActixAdminSearchBackend,
+    /// Order by search relevance ahead of the sort columns when the backend
+    /// ranks results and `search` is not blank. Ignored with a keyset cursor.
+    pub search_by_relevance: bool,
+    /// SQL expressions of the sortable computed columns by name, which
+    /// `sort` may then refer to; see [`ActixAdminComputedColumn::sort_expr`].
+    pub computed_sort_exprs: HashMap<String, String>,
     pub tenant_ref: Option<i32>,
 }
 
+/// A page of the tom-select lookup behind `/<entity>/search`; see
+/// [`ActixAdminViewModelTrait::lookup`].
+pub struct ActixAdminLookupParams {
+    pub search: String,
+    /// How `search` is matched against the entity's lookup columns.
+    pub search_backend: ActixAdminSearchBackend,
+    /// Order by search relevance ahead of the primary key.
+    pub search_by_relevance: bool,
+    pub offset: u64,
+    pub limit: u64,
+    pub tenant_ref: Option<i32>,
+}
+
+/// Position in a keyset-paginated list, used instead of `page` for entities
+/// with [`ActixAdminViewModel::keyset_pagination`] enabled.
+///
+/// The cursor carries the primary key of the boundary row; `list` orders by
+/// the sort columns plus the primary key and continues strictly after (or
+/// before) that row's `(sort values, primary key)` tuple. No `COUNT(*)` is
+/// issued. To detect whether another page exists, `list` returns up to
+/// `entities_per_page + 1` rows in display order: the surplus row is the last
+/// one for `First` / `After` and the first one for `Before`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActixAdminCursor {
+    First,
+    After(String),
+    Before(String),
+}
+
+impl ActixAdminCursor {
+    /// Primary key of the boundary row, if any.
+    pub fn boundary_key(&self) -> Option<&str> {
+        match self {
+            ActixAdminCursor::First => None,
+            ActixAdminCursor::After(key) | ActixAdminCursor::Before(key) => Some(key),
+        }
+    }
+
+    /// `true` when rows are scanned backwards (towards the first page).
+    pub fn is_backward(&self) -> bool {
+        matches!(self, ActixAdminCursor::Before(_))
+    }
+}
+
+impl ActixAdminViewModelParams {
+    /// The columns to order by, falling back to `sort_by` / `sort_order`
+    /// for callers that only fill in the single-column fields.
+    pub fn sort_columns(&self) -> Vec<SortColumn> {
+        if self.sort.is_empty() {
+            vec![SortColumn::new(
+                self.sort_by.clone(),
+                self.sort_order.clone(),
+            )]
+        } else {
+            self.sort.clone()
+        }
+    }
+}
+
 /// Blanket bound for anything usable as an entity primary key in the admin.
 ///
 /// This is what powers the `ActixAdminViewModelTrait::Id` associated type,
@@ -37,8 +121,10 @@ impl<T> ActixAdminPrimaryKey for T where
 {
 }
 
+/// The create, edit and delete routes run the entity's
+/// [`ActixAdminModelHooksTrait`] around their writes.
 #[async_trait(?Send)]
-pub trait ActixAdminViewModelTrait {
+pub trait ActixAdminViewModelTrait: ActixAdminModelHooksTrait {
     /// The primary-key type of this entity. Defaults to `i32` in the derive
     /// macro output; override by having a `#[actix_admin(primary_key)]` field
     /// with a different type (e.g. `Uuid`, `i64`, `String`).
@@ -49,14 +135,26 @@ pub trait ActixAdminViewModelTrait {
         params: &ActixAdminViewModelParams,
     ) -> Result<(Option<u64>, Vec<ActixAdminModel>), ActixAdminError>;
 
-    // TODO: Replace return value with proper Result Type containing Ok or Err
-    async fn create_entity(
+    /// `(primary key, label)` pairs matching `params.search` on the
+    /// `lookup` fields (the `searchable` ones by default), ordered by
+    /// relevance if requested, then primary key. Loads neither foreign keys nor more columns than the label needs.
+    async fn lookup(
         db: &DatabaseConnection,
+        params: &ActixAdminLookupParams,
+    ) -> Result<Vec<(String, String)>, ActixAdminError>;
+
+    // The writes and the `get_entity` that reads the stored entity for them
+    // take any connection, so that the routes can run them together with
+    // the hooks in one transaction.
+
+    // TODO: Replace return value with proper Result Type containing Ok or Err
+    async fn create_entity<C: ConnectionTrait>(
+        db: &C,
         model: ActixAdminModel,
         tenant_ref: Option<i32>,
     ) -> Result<ActixAdminModel, ActixAdminError>;
-    async fn delete_entity(
-        db: &DatabaseConnection,
+    async fn delete_entity<C: ConnectionTrait>(
+        db: &C,
         id: Self::Id,
         tenant_ref: Option<i32>,
     ) -> Result<bool, ActixAdminError>;
@@ -64,9 +162,10 @@ pub trait ActixAdminViewModelTrait {
     /// Bulk-delete many entities in a single query. Default implementation
     /// falls back to a per-id loop over `delete_entity`, so existing
     /// implementations keep working; the derive-macro override does a single
-    /// `DELETE ... WHERE pk IN (...)` query.
-    async fn delete_entities(
-        db: &DatabaseConnection,
+    /// `DELETE ... WHERE pk IN (...)` query. Run it on a transaction for the
+    /// loop to be atomic, as the bulk delete route does.
+    async fn delete_entities<C: ConnectionTrait>(
+        db: &C,
         ids: &[Self::Id],
         tenant_ref: Option<i32>,
     ) -> Result<u64, ActixAdminError> {
@@ -79,13 +178,13 @@ pub trait ActixAdminViewModelTrait {
         Ok(deleted)
     }
 
-    async fn get_entity(
-        db: &DatabaseConnection,
+    async fn get_entity<C: ConnectionTrait>(
+        db: &C,
         id: Self::Id,
         tenant_ref: Option<i32>,
     ) -> Result<ActixAdminModel, ActixAdminError>;
-    async fn edit_entity(
-        db: &DatabaseConnection,
+    async fn edit_entity<C: ConnectionTrait>(
+        db: &C,
         id: Self::Id,
         model: ActixAdminModel,
         tenant_ref: Option<i32>,
@@ -97,7 +196,17 @@ pub trait ActixAdminViewModelTrait {
     async fn get_viewmodel_filter(
         db: &DatabaseConnection,
     ) -> HashMap<String, ActixAdminViewModelFilter>;
-    async fn validate_entity(model: &mut ActixAdminModel, db: &DatabaseConnection);
+    /// Validate `model` for `operation` and fill in its errors, see
+    /// [`crate::model::ActixAdminModelAsyncValidationTrait`].
+    async fn validate_entity(
+        model: &mut ActixAdminModel,
+        db: &DatabaseConnection,
+        session: &Session,
+        operation: &ActixAdminOperation,
+    );
+    /// Fill in [`ActixAdminModel::fk_values`], the labels of the model's
+    /// foreign keys.
+    async fn load_fk_values(model: &mut ActixAdminModel, db: &DatabaseConnection);
 
     fn get_entity_name() -> String;
 }
@@ -117,6 +226,108 @@ pub struct ActixAdminBulkAction {
     pub confirm: Option<String>,
 }
 
+/// Computes a computed column for a whole page of models at once, returning
+/// one display value per model in the same order. Gets the database
+/// connection, e.g. to count related rows in a single query.
+pub type ActixAdminComputeFn =
+    for<'a> fn(
+        &'a DatabaseConnection,
+        &'a [ActixAdminModel],
+    ) -> LocalBoxFuture<'a, Result<Vec<String>, ActixAdminError>>;
+
+/// How the values of an [`ActixAdminComputedColumn`] are computed.
+#[derive(Clone, Debug)]
+pub enum ActixAdminComputedValue {
+    /// From the model alone.
+    Model(fn(&ActixAdminModel) -> String),
+    /// Batched per page, with database access.
+    Batched(ActixAdminComputeFn),
+}
+
+/// A virtual column ("comment count", "full name", ...) shown after the
+/// fields in the list, the show page and the CSV export. Add it to
+/// [`ActixAdminViewModel::computed_columns`].
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminComputedColumn {
+    /// Identifier of the column, used as the sort key and the export header.
+    pub name: String,
+    /// Column header rendered in the list and on the show page.
+    pub label: String,
+    #[serde(skip)]
+    pub value: ActixAdminComputedValue,
+    /// SQL expression the list is ordered by when sorting by the column,
+    /// e.g. `(SELECT COUNT(*) FROM comment WHERE comment.post_id = post.id)`.
+    /// The column cannot be sorted without one, nor with keyset pagination.
+    pub sort_expr: Option<String>,
+}
+
+impl ActixAdminComputedColumn {
+    /// A column computed from the model alone.
+    pub fn new(
+        name: impl Into<String>,
+        label: impl Into<String>,
+        compute: fn(&ActixAdminModel) -> String,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            value: ActixAdminComputedValue::Model(compute),
+            sort_expr: None,
+        }
+    }
+
+    /// A column computed for a page of models at once, see
+    /// [`ActixAdminComputeFn`].
+    pub fn batched(
+        name: impl Into<String>,
+        label: impl Into<String>,
+        compute: ActixAdminComputeFn,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            value: ActixAdminComputedValue::Batched(compute),
+            sort_expr: None,
+        }
+    }
+
+    /// Make the column sortable by the SQL expression `sort_expr`.
+    pub fn sortable_by(mut self, sort_expr: impl Into<String>) -> Self {
+        self.sort_expr = Some(sort_expr.into());
+        self
+    }
+}
+
+/// A tab of the create / edit form and the show page, see
+/// [`ActixAdminViewModel::form_layout`].
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminFormTab {
+    /// Empty for the fields without a `tab`. A layout with a single tab is
+    /// rendered without a tab bar.
+    pub name: String,
+    /// Shown first: the first tab with a validation error, else the first.
+    pub active: bool,
+    pub has_errors: bool,
+    pub fieldsets: Vec<ActixAdminFieldset>,
+}
+
+/// A named section of a form tab.
+#[derive(Clone, Debug, Serialize)]
+pub struct ActixAdminFieldset {
+    /// Empty for the fields without a `fieldset`, rendered without a legend.
+    pub name: String,
+    /// Listed in [`ActixAdminViewModel::collapsible_fieldsets`].
+    pub collapsible: bool,
+    pub has_errors: bool,
+    /// Fields sharing a `form_row` are placed side by side; every other
+    /// field is a row of its own.
+    pub rows: Vec<Vec<&'static ActixAdminViewModelField>>,
+}
+
+/// Computes the value a create form field starts with, e.g. the logged-in
+/// user from the session. `None` falls back to the field's `default_value`.
+pub type ActixAdminFieldDefaultFn = fn(&Session) -> Option<String>;
+
 #[derive(Clone)]
 pub struct ActixAdminViewModel {
     pub entity_name: String,
@@ -136,9 +347,200 @@ pub struct ActixAdminViewModel {
     pub user_can_export: Option<fn(&Session) -> bool>,
     pub default_show_aside: bool,
     pub inline_edit: bool,
+    /// Use keyset (cursor) pagination with previous/next links instead of
+    /// numbered pages. Skips the `COUNT(*)` and `OFFSET` of classic paging,
+    /// which keeps deep pages fast on very large tables.
+    pub keyset_pagination: bool,
+    /// Backend used for the list search box and the tom-select `/search`
+    /// endpoint. Defaults to `LIKE '%term%'` on every searchable column.
+    pub search_backend: ActixAdminSearchBackend,
+    /// Order search results by relevance (best match first) when the search
+    /// backend supports ranking. Column sorting then only breaks ties.
+    pub search_by_relevance: bool,
+    /// Names of the filters from `ActixAdminModelFilterTrait::get_filter`,
+    /// which the structured list search accepts as `name:value` keys.
+    pub filter_names: Vec<String>,
     /// Bulk actions registered for this entity. Cloned into the ViewModel by
     /// the builder when `add_bulk_action_for_entity` is called.
     pub bulk_actions: Vec<ActixAdminBulkAction>,
+    /// Virtual columns shown after the fields, see
+    /// [`ActixAdminComputedColumn`].
+    pub computed_columns: Vec<ActixAdminComputedColumn>,
+    /// Fieldsets rendered as collapsible sections, collapsed unless they
+    /// contain a validation error.
+    pub collapsible_fieldsets: Vec<String>,
+    /// Values the create form starts with, computed per request by field
+    /// name, see [`ActixAdminViewModel::new_model`].
+    pub field_defaults: HashMap<String, ActixAdminFieldDefaultFn>,
+    /// Fields left empty when a record is duplicated, e.g. unique slugs.
+    pub non_copyable_fields: Vec<String>,
+    /// Copy the uploaded files of a duplicated record to new filenames, so
+    /// that deleting the file of one record doesn't break the other. When
+    /// `false` the duplicate starts without files.
+    pub duplicate_copies_files: bool,
+}
+
+impl ActixAdminViewModel {
+    /// Fill in [`ActixAdminModel::computed_values`] of `models` for every
+    /// computed column.
+    pub async fn compute_columns(
+        &self,
+        db: &DatabaseConnection,
+        models: &mut [ActixAdminModel],
+    ) -> Result<(), ActixAdminError> {
+        for column in &self.computed_columns {
+            let values = match column.value {
+                ActixAdminComputedValue::Model(compute) => models.iter().map(compute).collect(),
+                ActixAdminComputedValue::Batched(compute) => compute(db, models).await?,
+            };
+            if values.len() != models.len() {
+                return Err(ActixAdminError::internal(format!(
+                    "computed column `{}` returned {} values for {} models",
+                    column.name,
+                    values.len(),
+                    models.len()
+                )));
+            }
+            for (model, value) in models.iter_mut().zip(values) {
+                model.computed_values.insert(column.name.clone(), value);
+            }
+        }
+        Ok(())
+    }
+
+    /// The model the create form starts with. Each field takes its value
+    /// from `query_string` (`?post_id=5`), else from [`Self::field_defaults`],
+    /// else from its `default_value` attribute. Keys that name no field, and
+    /// password fields, are ignored.
+    pub fn new_model(&self, session: &Session, query_string: &str) -> ActixAdminModel {
+        let prefill: Vec<(String, String)> =
+            serde_urlencoded::from_str(query_string).unwrap_or_default();
+        let mut model = ActixAdminModel::create_empty();
+
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| field.field_type != ActixAdminViewModelFieldType::Password)
+        {
+            let value = prefill
+                .iter()
+                .rev()
+                .find(|(key, _)| *key == field.field_name)
+                .map(|(_, value)| value.clone())
+                .or_else(|| {
+                    self.field_defaults
+                        .get(&field.field_name)
+                        .and_then(|default| default(session))
+                })
+                .or_else(|| field.default_value.clone());
+            if let Some(value) = value {
+                model.values.insert(field.field_name.clone(), value);
+            }
+        }
+        model
+    }
+
+    /// The model the create form of a duplicate of `source` starts with: its
+    /// values without the primary key, the tenant reference, passwords,
+    /// uploaded files and [`Self::non_copyable_fields`].
+    pub fn duplicate_model(&self, source: &ActixAdminModel) -> ActixAdminModel {
+        let mut model = ActixAdminModel::create_empty();
+
+        for field in self.fields.iter().filter(|field| {
+            !field.is_tenant_ref
+                && !matches!(
+                    field.field_type,
+                    ActixAdminViewModelFieldType::Password
+                        | ActixAdminViewModelFieldType::FileUpload
+                        | ActixAdminViewModelFieldType::Image
+                )
+                && !self.non_copyable_fields.contains(&field.field_name)
+        }) {
+            if let Some(value) = source.values.get(&field.field_name) {
+                model.values.insert(field.field_name.clone(), value.clone());
+            }
+            if let Some(value) = source.fk_values.get(&field.field_name) {
+                model
+                    .fk_values
+                    .insert(field.field_name.clone(), value.clone());
+            }
+        }
+        model
+    }
+
+    /// Group the fields into tabs, fieldsets and rows by their `tab`,
+    /// `fieldset` and `form_row`, each in order of first appearance. The
+    /// errors of `model` decide which tab is active and which collapsible
+    /// fieldsets are opened.
+    pub fn form_layout(&self, model: &ActixAdminModel) -> Vec<ActixAdminFormTab> {
+        let mut tabs: Vec<ActixAdminFormTab> = Vec::new();
+        // Row keys of the fieldset's rows, parallel to `rows`.
+        let mut row_keys: Vec<Vec<Vec<Option<&str>>>> = Vec::new();
+
+        for field in self.fields {
+            let has_error = model.errors.contains_key(&field.field_name)
+                || model.custom_errors.contains_key(&field.field_name);
+            let tab_name = field.tab.clone().unwrap_or_default();
+            let fieldset_name = field.fieldset.clone().unwrap_or_default();
+
+            let tab_idx = match tabs.iter().position(|t| t.name == tab_name) {
+                Some(idx) => idx,
+                None => {
+                    tabs.push(ActixAdminFormTab {
+                        name: tab_name,
+                        active: false,
+                        has_errors: false,
+                        fieldsets: Vec::new(),
+                    });
+                    row_keys.push(Vec::new());
+                    tabs.len() - 1
+                }
+            };
+            let tab = &mut tabs[tab_idx];
+            tab.has_errors |= has_error;
+
+            let fieldset_idx = match tab.fieldsets.iter().position(|f| f.name == fieldset_name) {
+                Some(idx) => idx,
+                None => {
+                    tab.fieldsets.push(ActixAdminFieldset {
+                        collapsible: self.collapsible_fieldsets.contains(&fieldset_name),
+                        name: fieldset_name,
+                        has_errors: false,
+                        rows: Vec::new(),
+                    });
+                    row_keys[tab_idx].push(Vec::new());
+                    tab.fieldsets.len() - 1
+                }
+            };
+            let fieldset = &mut tab.fieldsets[fieldset_idx];
+            fieldset.has_errors |= has_error;
+
+            let keys = &mut row_keys[tab_idx][fieldset_idx];
+            let row_key = field.form_row.as_deref();
+            match keys.iter().position(|k| row_key.is_some() && *k == row_key) {
+                Some(row) => fieldset.rows[row].push(field),
+                None => {
+                    fieldset.rows.push(vec![field]);
+                    keys.push(row_key);
+                }
+            }
+        }
+
+        let active = tabs.iter().position(|t| t.has_errors).unwrap_or(0);
+        if let Some(tab) = tabs.get_mut(active) {
+            tab.active = true;
+        }
+        tabs
+    }
+
+    /// The SQL expressions of the sortable computed columns, see
+    /// [`ActixAdminViewModelParams::computed_sort_exprs`].
+    pub fn computed_sort_exprs(&self) -> HashMap<String, String> {
+        self.computed_columns
+            .iter()
+            .filter_map(|column| Some((column.name.clone(), column.sort_expr.clone()?)))
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -149,6 +551,7 @@ pub struct ActixAdminViewModelSerializable {
     pub show_search: bool,
     pub default_show_aside: bool,
     pub inline_edit: bool,
+    pub keyset_pagination: bool,
     /// Serialized permission flags, resolved for the current session. Filled
     /// in per-request by `add_default_context` since the fn hooks themselves
     /// are not serializable.
@@ -163,6 +566,7 @@ pub struct ActixAdminViewModelSerializable {
     #[serde(default)]
     pub can_export: bool,
     pub bulk_actions: Vec<ActixAdminBulkAction>,
+    pub computed_columns: Vec<ActixAdminComputedColumn>,
 }
 
 /// Comparison operator applied by an advanced filter. Encoded on the wire as
@@ -232,10 +636,10 @@ impl std::str::FromStr for ActixAdminFilterOperator {
             "not_equals" | "ne" | "!=" => Ok(Self::NotEquals),
             "contains" | "like" => Ok(Self::Contains),
             "not_contains" | "not_like" => Ok(Self::NotContains),
-            "gt" | ">" => Ok(Self::GreaterThan),
-            "lt" | "<" => Ok(Self::LessThan),
-            "gte" | ">=" => Ok(Self::GreaterEquals),
-            "lte" | "<=" => Ok(Self::LessEquals),
+            "gt" | "greater_than" | ">" => Ok(Self::GreaterThan),
+            "lt" | "less_than" | "<" => Ok(Self::LessThan),
+            "gte" | "greater_equals" | ">=" => Ok(Self::GreaterEquals),
+            "lte" | "less_equals" | "<=" => Ok(Self::LessEquals),
             "is_null" | "empty" => Ok(Self::IsNull),
             "is_not_null" | "not_empty" => Ok(Self::IsNotNull),
             "in" | "in_list" => Ok(Self::InList),
@@ -248,6 +652,10 @@ impl std::str::FromStr for ActixAdminFilterOperator {
 pub struct ActixAdminViewModelFilter {
     pub name: String,
     pub value: Option<String>,
+    /// Every non-empty value submitted for the filter, in order; more than
+    /// one for multi-select filters. `value` is the first of them.
+    #[serde(default)]
+    pub selected: Vec<String>,
     pub foreign_key: Option<String>,
     pub values: Option<Vec<(String, String)>>,
     pub filter_type: Option<ActixAdminModelFilterType>,
@@ -261,6 +669,74 @@ pub struct ActixAdminViewModelFilter {
     pub operator: Option<ActixAdminFilterOperator>,
 }
 
+impl ActixAdminViewModelFilter {
+    /// The filter generated for a field marked `#[actix_admin(filter)]`,
+    /// typed after the field: text fields match by substring, numbers and
+    /// dates compare, booleans are tri-state and `select_list` /
+    /// `foreign_key` fields pick any number of their values. Optional fields can
+    /// also be filtered on being empty. `values` are left for the caller to
+    /// load.
+    pub fn from_field(field: &ActixAdminViewModelField) -> Self {
+        use ActixAdminFilterOperator::*;
+
+        let comparisons = vec![
+            Equals,
+            NotEquals,
+            GreaterThan,
+            GreaterEquals,
+            LessThan,
+            LessEquals,
+        ];
+        let has_foreign_key = !field.foreign_key.is_empty();
+        let (filter_type, mut operators) = match field.field_type {
+            _ if has_foreign_key && field.use_tom_select_callback => (
+                ActixAdminModelFilterType::TomSelectSearch,
+                vec![Equals, NotEquals],
+            ),
+            _ if has_foreign_key || !field.select_list.is_empty() => (
+                ActixAdminModelFilterType::MultiSelectList,
+                vec![Equals, NotEquals],
+            ),
+            ActixAdminViewModelFieldType::Checkbox => {
+                (ActixAdminModelFilterType::Checkbox, Vec::new())
+            }
+            ActixAdminViewModelFieldType::Number | ActixAdminViewModelFieldType::Decimal => {
+                (ActixAdminModelFilterType::Number, comparisons)
+            }
+            ActixAdminViewModelFieldType::Uuid => {
+                (ActixAdminModelFilterType::Text, vec![Equals, NotEquals])
+            }
+            ActixAdminViewModelFieldType::Date => (ActixAdminModelFilterType::Date, comparisons),
+            ActixAdminViewModelFieldType::DateTime => {
+                (ActixAdminModelFilterType::DateTime, comparisons)
+            }
+            ActixAdminViewModelFieldType::Time => (ActixAdminModelFilterType::Time, comparisons),
+            _ => (
+                ActixAdminModelFilterType::Text,
+                vec![Contains, NotContains, Equals, NotEquals],
+            ),
+        };
+        if field.is_option {
+            // Without an operator picker a checkbox filter only compares.
+            if operators.is_empty() {
+                operators.push(Equals);
+            }
+            operators.extend([IsNull, IsNotNull]);
+        }
+
+        ActixAdminViewModelFilter {
+            name: field.field_name.clone(),
+            value: None,
+            selected: Vec::new(),
+            foreign_key: has_foreign_key.then(|| field.foreign_key.clone()),
+            values: None,
+            filter_type: Some(filter_type),
+            operators,
+            operator: None,
+        }
+    }
+}
+
 impl ActixAdminViewModelSerializable {
     /// Build a serializable snapshot of `entity` with **all `can_*` flags set
     /// to `false`**. Call sites that know the current session must set the
@@ -278,12 +754,14 @@ impl ActixAdminViewModelSerializable {
             show_search: entity.show_search,
             default_show_aside: entity.default_show_aside,
             inline_edit: entity.inline_edit,
+            keyset_pagination: entity.keyset_pagination,
             can_create: false,
             can_edit: false,
             can_delete: false,
             can_view_details: false,
             can_export: false,
             bulk_actions: entity.bulk_actions.clone(),
+            computed_columns: entity.computed_columns.clone(),
         }
     }
 }
@@ -299,7 +777,11 @@ impl From<ActixAdminViewModel> for ActixAdminViewModelSerializable {
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum ActixAdminViewModelFieldType {
+    /// An integer of any width.
     Number,
+    /// A fractional number (`f32`, `f64`, `Decimal`), typed as text so that
+    /// a decimal comma or thousands separators can be entered.
+    Decimal,
     Text,
     TextArea,
     Checkbox,
@@ -321,6 +803,72 @@ pub enum ActixAdminViewModelFieldType {
     /// A textarea backed by a Markdown WYSIWYG editor (EasyMDE) in the
     /// create/edit form.
     RichText,
+    Uuid,
+    /// A JSON document (`Json` columns or fields marked `json`), edited in a
+    /// highlighted editor and shown as a collapsible tree.
+    Json,
+    /// A write-only secret, entered twice in an empty `type=password` input
+    /// and never rendered back.
+    Password,
+}
+
+/// A rule of a field's `visible_when` / `required_when` attribute: holds when
+/// the submitted value of `field` is one of `values`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActixAdminFieldCondition {
+    pub field: String,
+    pub values: Vec<String>,
+}
+
+impl ActixAdminFieldCondition {
+    /// Parse `"<field> = <value>"` or `"<field> in (<value>, ...)"`. Values
+    /// may be quoted. Panics on a malformed rule.
+    pub fn parse(field_name: &str, rule: &str) -> Self {
+        let invalid = || -> ! {
+            panic!(
+                "invalid condition `{rule}` of field `{field_name}`, expected `field = value` or `field in (value, ...)`"
+            )
+        };
+        let unquote = |value: &str| {
+            let value = value.trim();
+            value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+                .unwrap_or(value)
+                .to_string()
+        };
+
+        // ` in ` first, as a value of the list may contain `=`.
+        let (field, values) = if let Some((field, list)) = rule.split_once(" in ") {
+            let list = list.trim();
+            let list = list
+                .strip_prefix('(')
+                .and_then(|l| l.strip_suffix(')'))
+                .unwrap_or_else(|| invalid());
+            (field, list.split(',').map(unquote).collect())
+        } else if let Some((field, value)) = rule.split_once('=') {
+            (field, vec![unquote(value)])
+        } else {
+            invalid()
+        };
+
+        let field = field.trim();
+        if field.is_empty() || field.contains(char::is_whitespace) {
+            invalid();
+        }
+        ActixAdminFieldCondition {
+            field: field.to_string(),
+            values,
+        }
+    }
+
+    /// Whether the submitted `values` of a form satisfy the rule. A missing
+    /// value, e.g. of an unchecked checkbox, counts as empty.
+    pub fn is_met(&self, values: &HashMap<String, String>) -> bool {
+        let value = values.get(&self.field).map(String::as_str).unwrap_or("");
+        self.values.iter().any(|v| v == value)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -345,6 +893,60 @@ pub struct ActixAdminViewModelField {
     /// fields are still shown in the show view and in the edit form (disabled).
     #[serde(default)]
     pub readonly: bool,
+    /// Whether the field has an auto-generated list filter, see
+    /// [`ActixAdminViewModelFilter::from_field`].
+    #[serde(default)]
+    pub filter: bool,
+    /// JSON Schema the value of a `Json` field is validated against, see
+    /// [`crate::json_schema`].
+    #[serde(skip_serializing, skip_deserializing)]
+    pub json_schema: Option<serde_json::Value>,
+    /// Tera partials replacing the built-in rendering of the list cell, the
+    /// show page value and the form widget, see
+    /// [`crate::routes::render_field_templates`].
+    #[serde(default)]
+    pub list_template: Option<String>,
+    #[serde(default)]
+    pub show_template: Option<String>,
+    #[serde(default)]
+    pub form_template: Option<String>,
+    /// Placement in the create / edit form and on the show page, see
+    /// [`ActixAdminViewModel::form_layout`].
+    #[serde(default)]
+    pub tab: Option<String>,
+    #[serde(default)]
+    pub fieldset: Option<String>,
+    #[serde(default)]
+    pub form_row: Option<String>,
+    /// Value the create form starts with, see
+    /// [`ActixAdminViewModel::new_model`].
+    #[serde(default)]
+    pub default_value: Option<String>,
+    /// The field is only shown, validated and saved while the rule holds,
+    /// see [`ActixAdminModel::hide_fields`].
+    #[serde(default)]
+    pub visible_when: Option<ActixAdminFieldCondition>,
+    /// The field cannot be left empty while the rule holds.
+    #[serde(default)]
+    pub required_when: Option<ActixAdminFieldCondition>,
+    /// Declarative constraints checked by `validate_model`, see
+    /// [`crate::constraints`]. Rendered as the `minlength`, `maxlength`,
+    /// `min`, `max` and `pattern` attributes of the form input.
+    #[serde(default)]
+    pub min_length: Option<u32>,
+    #[serde(default)]
+    pub max_length: Option<u32>,
+    #[serde(default)]
+    pub min: Option<String>,
+    #[serde(default)]
+    pub max: Option<String>,
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// `regex`, anchored to match the whole value.
+    #[serde(skip_serializing, skip_deserializing)]
+    pub compiled_regex: Option<Regex>,
+    #[serde(default)]
+    pub one_of: Vec<String>,
 }
 
 impl ActixAdminViewModelFieldType {
@@ -359,7 +961,12 @@ impl ActixAdminViewModelFieldType {
         is_url: bool,
         is_email: bool,
         is_wysiwyg: bool,
+        is_json: bool,
+        is_password: bool,
     ) -> ActixAdminViewModelFieldType {
+        if is_password {
+            return ActixAdminViewModelFieldType::Password;
+        }
         if !select_list.is_empty() {
             return ActixAdminViewModelFieldType::SelectList;
         }
@@ -384,16 +991,26 @@ impl ActixAdminViewModelFieldType {
         if is_email {
             return ActixAdminViewModelFieldType::Email;
         }
+        if is_json {
+            return ActixAdminViewModelFieldType::Json;
+        }
 
-        match type_path {
-            "i32" => ActixAdminViewModelFieldType::Number,
-            "i64" => ActixAdminViewModelFieldType::Number,
-            "usize" => ActixAdminViewModelFieldType::Number,
+        // Match on the last path segment so that `rust_decimal::Decimal`
+        // and `Decimal` are the same type.
+        let type_name = type_path.rsplit("::").next().unwrap_or(type_path).trim();
+        match type_name {
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+            | "u128" | "usize" => ActixAdminViewModelFieldType::Number,
+            "f32" | "f64" | "Decimal" => ActixAdminViewModelFieldType::Decimal,
             "String" => ActixAdminViewModelFieldType::Text,
             "bool" => ActixAdminViewModelFieldType::Checkbox,
+            "Uuid" => ActixAdminViewModelFieldType::Uuid,
             "DateTimeWithTimeZone" => ActixAdminViewModelFieldType::DateTime,
-            "DateTime" => ActixAdminViewModelFieldType::DateTime,
-            "Date" => ActixAdminViewModelFieldType::Date,
+            "DateTime" | "NaiveDateTime" => ActixAdminViewModelFieldType::DateTime,
+            "Date" | "NaiveDate" => ActixAdminViewModelFieldType::Date,
+            "Time" | "NaiveTime" => ActixAdminViewModelFieldType::Time,
+            "Json" | "JsonValue" => ActixAdminViewModelFieldType::Json,
+            "Value" if type_path.contains("serde_json") => ActixAdminViewModelFieldType::Json,
             _ => ActixAdminViewModelFieldType::Text,
         }
     }
@@ -0,0 +1,83 @@
+//! Integration tests for `ActixAdminModelAsyncValidationTrait`.
+
+mod test_setup;
+
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::Serialize;
+
+use test_setup::prelude::*;
+use test_setup::Comment;
+
+#[derive(Serialize)]
+struct CommentForm {
+    insert_date: &'static str,
+    comment: &'static str,
+    user: &'static str,
+    my_decimal: &'static str,
+    post_id: &'static str,
+}
+
+impl CommentForm {
+    fn new(comment: &'static str, post_id: &'static str) -> Self {
+        CommentForm {
+            insert_date: "1977-04-01T14:00",
+            comment,
+            user: "test",
+            my_decimal: "200",
+            post_id,
+        }
+    }
+}
+
+async fn post(db: &DatabaseConnection, uri: &str, form: CommentForm) -> (bool, String) {
+    let (status, body) = post_form(db, uri, form).await;
+    (status.is_redirection(), body)
+}
+
+#[actix_web::test]
+async fn async_errors_prevent_the_create() {
+    let db = setup_db(true).await;
+
+    let (created, body) = post(
+        &db,
+        "/admin/comment/create_post_from_plaintext",
+        CommentForm::new("Test 1", "1"),
+    )
+    .await;
+    assert!(!created);
+    assert!(body.contains("The post already has this comment"), "{body}");
+    assert!(Comment::find_by_id(1000).one(&db).await.unwrap().is_none());
+
+    let (created, body) = post(
+        &db,
+        "/admin/comment/create_post_from_plaintext",
+        CommentForm::new("Test 1", "2"),
+    )
+    .await;
+    assert!(created, "{body}");
+}
+
+#[actix_web::test]
+async fn edits_are_validated_against_other_records() {
+    let db = setup_db(true).await;
+
+    // Saving comment 1 unchanged doesn't conflict with itself.
+    let (edited, body) = post(
+        &db,
+        "/admin/comment/edit_post_from_plaintext/1",
+        CommentForm::new("Test 1", "1"),
+    )
+    .await;
+    assert!(edited, "{body}");
+
+    let (edited, body) = post(
+        &db,
+        "/admin/comment/edit_post_from_plaintext/2",
+        CommentForm::new("Test 1", "1"),
+    )
+    .await;
+    assert!(!edited);
+    assert!(body.contains("The post already has this comment"), "{body}");
+    let comment = Comment::find_by_id(2).one(&db).await.unwrap().unwrap();
+    assert_eq!(comment.comment, "Test 2");
+}
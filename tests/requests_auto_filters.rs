@@ -0,0 +1,184 @@
+//! Integration tests for filters generated from `#[actix_admin(filter)]`.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter};
+
+use test_setup::prelude::*;
+use test_setup::{comment, Comment, Post};
+
+async fn get_body(db: &DatabaseConnection, uri: &str) -> String {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    assert_eq!(status.as_u16(), 200);
+    body
+}
+
+/// Number of rows the list returns for the sidebar filter query `filters`.
+async fn count<E: ActixAdminViewModelTrait>(
+    db: &DatabaseConnection,
+    view_model: &ActixAdminViewModel,
+    filters: &str,
+) -> usize {
+    let query = ListQuery::from_query(&format!("entities_per_page=1000&{filters}"), view_model);
+    let (_, entities) = E::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities.len()
+}
+
+#[actix_web::test]
+async fn filters_are_typed_after_the_field() {
+    let db = setup_db(false).await;
+    use ActixAdminFilterOperator::*;
+
+    let filters = Post::get_viewmodel_filter(&db).await;
+    let title = &filters["title"];
+    assert!(matches!(
+        title.filter_type,
+        Some(ActixAdminModelFilterType::Text)
+    ));
+    assert_eq!(
+        title.operators,
+        vec![Contains, NotContains, Equals, NotEquals]
+    );
+
+    let tea = &filters["tea_optional"];
+    assert!(matches!(
+        tea.filter_type,
+        Some(ActixAdminModelFilterType::MultiSelectList)
+    ));
+    assert_eq!(tea.operators, vec![Equals, NotEquals, IsNull, IsNotNull]);
+    let values = tea.values.clone().unwrap();
+    assert!(values.contains(&("EverydayTea".to_string(), "EverydayTea".to_string())));
+
+    let insert_date = &filters["insert_date"];
+    assert!(matches!(
+        insert_date.filter_type,
+        Some(ActixAdminModelFilterType::Date)
+    ));
+    assert!(insert_date.operators.contains(&GreaterEquals));
+    assert!(!filters.contains_key("text"));
+
+    let filters = Comment::get_viewmodel_filter(&db).await;
+    let is_visible = &filters["is_visible"];
+    assert!(matches!(
+        is_visible.filter_type,
+        Some(ActixAdminModelFilterType::Checkbox)
+    ));
+    assert!(is_visible.operators.is_empty());
+    let post_id = &filters["post_id"];
+    assert!(matches!(
+        post_id.filter_type,
+        Some(ActixAdminModelFilterType::TomSelectSearch)
+    ));
+    assert_eq!(post_id.foreign_key.as_deref(), Some("Post"));
+    // The hand-written filter is kept next to the generated ones.
+    assert!(filters.contains_key("User"));
+
+    assert!(ActixAdminViewModel::from(Post).default_show_aside);
+}
+
+#[actix_web::test]
+async fn sidebar_renders_generated_filters() {
+    let db = setup_db(false).await;
+
+    let body = get_body(
+        &db,
+        "/admin/post/list?filter_title=Test&filter_title__op=not_contains",
+    )
+    .await;
+    assert!(body.contains("name=\"filter_title__op\""), "{body}");
+    assert!(
+        body.contains("<option value=\"not_contains\" selected>"),
+        "{body}"
+    );
+    assert!(body.contains("<option value=\"BreakfastTea\""), "{body}");
+    assert!(
+        body.contains("type=\"date\" placeholder=\"\" name=\"filter_insert_date\""),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn generated_filters_apply_their_operator() {
+    let db = setup_db(true).await;
+    let post = ActixAdminViewModel::from(Post);
+    let comment = ActixAdminViewModel::from(Comment);
+
+    assert_eq!(
+        count::<Post>(&db, &post, "filter_title=Test+15&filter_title__op=contains").await,
+        11
+    );
+    assert_eq!(
+        count::<Post>(&db, &post, "filter_title=Test+15&filter_title__op=equals").await,
+        1
+    );
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_tea_optional=&filter_tea_optional__op=is_null"
+        )
+        .await,
+        999
+    );
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_tea_optional=BreakfastTea&filter_tea_optional__op=equals"
+        )
+        .await,
+        0
+    );
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_insert_date=2000-01-01&filter_insert_date__op=lt"
+        )
+        .await,
+        0
+    );
+    // The sidebar submits operators by their serialized name.
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_insert_date=2000-01-01&filter_insert_date__op=greater_than"
+        )
+        .await,
+        999
+    );
+
+    let visible = Comment::find()
+        .filter(comment::Column::IsVisible.eq(true))
+        .count(&db)
+        .await
+        .unwrap() as usize;
+    assert_eq!(
+        count::<Comment>(&db, &comment, "filter_is_visible=1").await,
+        visible
+    );
+    assert_eq!(
+        count::<Comment>(&db, &comment, "filter_is_visible=0").await,
+        999 - visible
+    );
+    // `datetime-local` inputs submit without seconds.
+    assert_eq!(
+        count::<Comment>(
+            &db,
+            &comment,
+            "filter_insert_date=2000-01-01T10:00&filter_insert_date__op=gte"
+        )
+        .await,
+        999
+    );
+    assert_eq!(
+        count::<Comment>(&db, &comment, "filter_post_id=15&filter_post_id__op=equals").await,
+        1
+    );
+}
@@ -0,0 +1,229 @@
+//! Integration tests for decimal, floating point, UUID, time and wider
+//! integer columns.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use chrono::NaiveTime;
+use sea_orm::prelude::{Decimal, Uuid};
+use sea_orm::{DatabaseConnection, DbBackend, EntityTrait, QueryFilter, QueryTrait, Value};
+use serde::Serialize;
+
+use test_setup::prelude::*;
+use test_setup::{comment, Comment};
+
+#[derive(Serialize)]
+struct CommentForm {
+    insert_date: &'static str,
+    comment: &'static str,
+    user: &'static str,
+    is_visible: &'static str,
+    my_decimal: &'static str,
+    ratio: &'static str,
+    reference: &'static str,
+    reminder: &'static str,
+    priority: &'static str,
+    views: &'static str,
+}
+
+async fn get_body(db: &DatabaseConnection, uri: &str) -> String {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    assert_eq!(status.as_u16(), 200);
+    body
+}
+
+async fn post_create(db: &DatabaseConnection, form: CommentForm) -> bool {
+    let (status, _) = post_form(db, "/admin/comment/create_post_from_plaintext", form).await;
+    status.is_redirection()
+}
+
+async fn list(db: &DatabaseConnection, params: &str) -> Vec<ActixAdminModel> {
+    let view_model = ActixAdminViewModel::from(Comment);
+    let query = ListQuery::from_query(&format!("entities_per_page=1000&{params}"), &view_model);
+    let (_, entities) = Comment::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities
+}
+
+#[actix_web::test]
+async fn field_types_are_detected() {
+    let view_model = ActixAdminViewModel::from(Comment);
+    let field_type = |name: &str| {
+        view_model
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .map(|f| f.field_type.clone())
+            .unwrap()
+    };
+
+    assert_eq!(
+        field_type("my_decimal"),
+        ActixAdminViewModelFieldType::Decimal
+    );
+    assert_eq!(field_type("ratio"), ActixAdminViewModelFieldType::Decimal);
+    assert_eq!(field_type("reference"), ActixAdminViewModelFieldType::Uuid);
+    assert_eq!(field_type("reminder"), ActixAdminViewModelFieldType::Time);
+    assert_eq!(field_type("priority"), ActixAdminViewModelFieldType::Number);
+    assert_eq!(field_type("views"), ActixAdminViewModelFieldType::Number);
+}
+
+#[actix_web::test]
+async fn create_parses_locale_numbers_and_times() {
+    let db = setup_db(false).await;
+    let created = post_create(
+        &db,
+        CommentForm {
+            insert_date: "1977-04-01T14:00",
+            comment: "typed",
+            user: "test",
+            is_visible: "true",
+            my_decimal: "1.234,5",
+            ratio: "0,25",
+            reference: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            reminder: "14:30",
+            priority: "-3",
+            views: "4000000000",
+        },
+    )
+    .await;
+    assert!(created);
+
+    let entity = Comment::find().one(&db).await.unwrap().unwrap();
+    assert_eq!(entity.my_decimal, Decimal::new(12345, 1));
+    assert_eq!(entity.ratio, Some(0.25));
+    assert_eq!(
+        entity.reference,
+        Some(Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap())
+    );
+    assert_eq!(entity.reminder, NaiveTime::from_hms_opt(14, 30, 0));
+    assert_eq!(entity.priority, Some(-3));
+    assert_eq!(entity.views, Some(4_000_000_000));
+
+    let body = get_body(&db, "/admin/comment/edit/1").await;
+    assert!(
+        body.contains("value=\"14:30:00\" name=\"reminder\""),
+        "{body}"
+    );
+    assert!(body.contains("inputmode=\"decimal\""), "{body}");
+    assert!(body.contains("type=\"time\""), "{body}");
+    assert!(body.contains("type=\"number\""), "{body}");
+    assert!(body.contains("spellcheck=\"false\""), "{body}");
+}
+
+#[actix_web::test]
+async fn invalid_values_are_rejected() {
+    let db = setup_db(false).await;
+    let created = post_create(
+        &db,
+        CommentForm {
+            insert_date: "1977-04-01T14:00",
+            comment: "typed",
+            user: "test",
+            is_visible: "true",
+            my_decimal: "200",
+            ratio: "abc",
+            reference: "not-a-uuid",
+            reminder: "25:99",
+            priority: "70000",
+            views: "-1",
+        },
+    )
+    .await;
+    assert!(!created);
+    assert!(Comment::find().one(&db).await.unwrap().is_none());
+}
+
+#[actix_web::test]
+async fn typed_columns_can_be_filtered() {
+    let db = setup_db(true).await;
+
+    let reference = Uuid::from_u128(7).to_string();
+    assert_eq!(
+        list(&db, &format!("filter_reference={reference}"))
+            .await
+            .len(),
+        1
+    );
+    assert_eq!(
+        list(
+            &db,
+            &format!("filter_reference={}", reference.replace('-', ""))
+        )
+        .await
+        .len(),
+        1
+    );
+
+    let view_model = ActixAdminViewModel::from(Comment);
+    let query = ListQuery::from_query("filter_reference=nope", &view_model);
+    assert!(Comment::list(&db, &query.to_view_model_params(None, true))
+        .await
+        .is_err());
+
+    // i % 24 == 5 for i in 1..1000
+    assert_eq!(list(&db, "filter_reminder=05:30").await.len(), 42);
+    assert_eq!(
+        list(&db, "filter_reminder=22:00&filter_reminder__op=gt")
+            .await
+            .len(),
+        82
+    );
+
+    assert_eq!(list(&db, "filter_ratio=2,5").await.len(), 1);
+    let query = ListQuery::from_query("filter_ratio=2,500", &view_model);
+    assert!(Comment::list(&db, &query.to_view_model_params(None, true))
+        .await
+        .is_err());
+    assert_eq!(
+        list(&db, "filter_ratio=240&filter_ratio__op=gte")
+            .await
+            .len(),
+        40
+    );
+    assert_eq!(list(&db, "filter_priority=0").await.len(), 199);
+    assert_eq!(
+        list(&db, "filter_views=9900&filter_views__op=gt")
+            .await
+            .len(),
+        9
+    );
+}
+
+#[actix_web::test]
+async fn typed_columns_can_be_sorted() {
+    let db = setup_db(true).await;
+
+    let entities = list(&db, "sort=-views").await;
+    assert_eq!(entities[0].values.get("views").unwrap(), "9990");
+
+    let entities = list(&db, "sort=-ratio").await;
+    assert_eq!(entities[0].values.get("ratio").unwrap(), "249.75");
+
+    let entities = list(&db, "sort=reminder,id").await;
+    assert_eq!(entities[0].values.get("reminder").unwrap(), "00:30:00");
+    assert_eq!(entities[0].primary_key.as_deref(), Some("24"));
+}
+
+#[actix_web::test]
+async fn decimal_filters_are_bound_exactly() {
+    // 2^53 + 1 and 0.1, which an `f64` can't hold.
+    for value in ["9007199254740993", "0,1"] {
+        let condition = column_filter_condition(
+            comment::Column::MyDecimal,
+            &ActixAdminFilterOperator::Equals,
+            Some(value),
+            DbBackend::Postgres,
+        )
+        .unwrap();
+        let statement = Comment::find().filter(condition).build(DbBackend::Postgres);
+        let expected: Decimal = value.replace(',', ".").parse().unwrap();
+        assert_eq!(
+            statement.values.unwrap().0,
+            vec![Value::Decimal(Some(expected))]
+        );
+    }
+}
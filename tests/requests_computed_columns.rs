@@ -0,0 +1,171 @@
+//! Integration tests for computed (virtual) columns.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use futures_util::future::LocalBoxFuture;
+use futures_util::FutureExt;
+use sea_orm::prelude::Decimal;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+use std::collections::HashMap;
+
+use test_setup::prelude::*;
+use test_setup::{comment, Comment, Post};
+
+fn shouted_title(model: &ActixAdminModel) -> String {
+    model
+        .values
+        .get("title")
+        .map(|title| title.to_uppercase())
+        .unwrap_or_default()
+}
+
+fn comment_count<'a>(
+    db: &'a DatabaseConnection,
+    models: &'a [ActixAdminModel],
+) -> LocalBoxFuture<'a, Result<Vec<String>, ActixAdminError>> {
+    async move {
+        let ids: Vec<i32> = models
+            .iter()
+            .filter_map(|m| m.primary_key.as_deref()?.parse().ok())
+            .collect();
+        let mut counts: HashMap<i32, usize> = HashMap::new();
+        for comment in Comment::find()
+            .filter(comment::Column::PostId.is_in(ids))
+            .all(db)
+            .await?
+        {
+            *counts
+                .entry(comment.post_id.unwrap_or_default())
+                .or_default() += 1;
+        }
+        Ok(models
+            .iter()
+            .map(|m| {
+                let id = m.primary_key.as_deref().and_then(|id| id.parse().ok());
+                id.and_then(|id| counts.get(&id))
+                    .copied()
+                    .unwrap_or(0)
+                    .to_string()
+            })
+            .collect())
+    }
+    .boxed_local()
+}
+
+async fn get(db: &DatabaseConnection, uri: &str) -> (u16, String) {
+    let mut builder = create_actix_admin_builder(false, None, false);
+    builder.add_computed_column_for_entity::<Post>(ActixAdminComputedColumn::new(
+        "shouted_title",
+        "Shouted Title",
+        shouted_title,
+    ));
+    builder.add_computed_column_for_entity::<Post>(
+        ActixAdminComputedColumn::batched("comment_count", "Comment Count", comment_count)
+            .sortable_by("(SELECT COUNT(*) FROM comment WHERE comment.post_id = post.id)"),
+    );
+    let (status, body) = call_admin(builder, db, test::TestRequest::get().uri(uri)).await;
+    (status.as_u16(), body)
+}
+
+async fn add_comments(db: &DatabaseConnection, post_id: i32, count: usize) {
+    for i in 0..count {
+        let row = comment::ActiveModel {
+            comment: Set(format!("Extra {i}")),
+            user: Set("me@home.com".to_string()),
+            insert_date: Set(chrono::Local::now().naive_utc()),
+            is_visible: Set(true),
+            my_decimal: Set(Decimal::new(1, 0)),
+            post_id: Set(Some(post_id)),
+            ..Default::default()
+        };
+        Comment::insert(row).exec(db).await.unwrap();
+    }
+}
+
+#[actix_web::test]
+async fn list_renders_computed_columns() {
+    let db = setup_db(true).await;
+    add_comments(&db, 2, 2).await;
+
+    let (status, body) = get(&db, "/admin/post/list?entities_per_page=3").await;
+    assert_eq!(status, 200, "{body}");
+    assert!(body.contains("Shouted Title"), "{body}");
+    assert!(
+        body.contains("onclick=\"sort_by('comment_count', event);\""),
+        "{body}"
+    );
+    assert!(!body.contains("sort_by('shouted_title'"), "{body}");
+    assert!(body.contains("<td>TEST 1</td>"), "{body}");
+    assert!(body.contains("<td>TEST 2</td><td>3</td>"), "{body}");
+}
+
+#[actix_web::test]
+async fn list_sorts_by_the_sql_expression() {
+    let db = setup_db(true).await;
+    add_comments(&db, 7, 4).await;
+    add_comments(&db, 5, 2).await;
+
+    let (status, body) = get(
+        &db,
+        "/admin/post/list?entities_per_page=3&sort_by=comment_count&sort_order=Desc",
+    )
+    .await;
+    assert_eq!(status, 200, "{body}");
+    let seven = body.find("<td>TEST 7</td><td>5</td>").expect(&body);
+    let five = body.find("<td>TEST 5</td><td>3</td>").expect(&body);
+    assert!(seven < five, "{body}");
+
+    // Columns without a sort expression are rejected like unknown fields.
+    let (status, _) = get(&db, "/admin/post/list?sort_by=shouted_title").await;
+    assert_eq!(status, 400);
+}
+
+#[actix_web::test]
+async fn keyset_lists_cannot_sort_by_computed_columns() {
+    let db = setup_db(true).await;
+    let builder = || {
+        let mut post_view_model = ActixAdminViewModel::from(Post);
+        post_view_model.keyset_pagination = true;
+        let mut builder = ActixAdminBuilder::new(ActixAdminConfiguration {
+            enable_csrf: false,
+            hash_password: hash_password_argon2,
+            ..Default::default()
+        });
+        builder.add_entity::<Post>(&post_view_model);
+        builder.add_computed_column_for_entity::<Post>(
+            ActixAdminComputedColumn::batched("comment_count", "Comment Count", comment_count)
+                .sortable_by("(SELECT COUNT(*) FROM comment WHERE comment.post_id = post.id)"),
+        );
+        builder
+    };
+    let list = |query: &str| test::TestRequest::get().uri(&format!("/admin/post/list?{query}"));
+
+    let (status, body) = call_admin(builder(), &db, list("entities_per_page=3")).await;
+    assert_eq!(status.as_u16(), 200, "{body}");
+    assert!(body.contains("Comment Count"), "{body}");
+    assert!(!body.contains("sort_by('comment_count'"), "{body}");
+
+    for query in ["sort_by=comment_count", "sort_by=comment_count&after=3"] {
+        let (status, _) = call_admin(builder(), &db, list(query)).await;
+        assert_eq!(status.as_u16(), 400, "{query}");
+    }
+}
+
+#[actix_web::test]
+async fn show_and_export_include_computed_columns() {
+    let db = setup_db(true).await;
+    add_comments(&db, 1, 1).await;
+
+    let (_, body) = get(&db, "/admin/post/show/1").await;
+    assert!(body.contains("Comment Count"), "{body}");
+    assert!(body.contains("<span>TEST 1</span>"), "{body}");
+    assert!(body.contains("<span>2</span>"), "{body}");
+
+    let (_, body) = get(&db, "/admin/post/export_csv?entities_per_page=2").await;
+    let rows: Vec<&str> = body.lines().collect();
+    assert!(rows[0].ends_with(",shouted_title,comment_count"), "{body}");
+    assert!(rows[1].ends_with(",TEST 1,2"), "{body}");
+    assert!(rows[2].ends_with(",TEST 2,1"), "{body}");
+}
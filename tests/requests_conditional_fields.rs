@@ -0,0 +1,158 @@
+//! Integration tests for `visible_when` / `required_when` field rules.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::EntityTrait;
+use serde::Serialize;
+
+use test_setup::prelude::*;
+use test_setup::Comment;
+
+#[derive(Serialize)]
+struct CommentForm {
+    insert_date: &'static str,
+    comment: &'static str,
+    user: &'static str,
+    my_decimal: &'static str,
+    status: &'static str,
+    review_note: &'static str,
+}
+
+impl CommentForm {
+    fn new(status: &'static str, review_note: &'static str) -> Self {
+        CommentForm {
+            insert_date: "1977-04-01T14:00",
+            comment: "conditional",
+            user: "test",
+            my_decimal: "200",
+            status,
+            review_note,
+        }
+    }
+}
+
+#[actix_web::test]
+async fn rules_are_parsed() {
+    assert_eq!(
+        ActixAdminFieldCondition::parse("note", "status = 2"),
+        ActixAdminFieldCondition {
+            field: "status".to_string(),
+            values: vec!["2".to_string()],
+        }
+    );
+    assert_eq!(
+        ActixAdminFieldCondition::parse("note", "status in (1, \"two words\")").values,
+        vec!["1".to_string(), "two words".to_string()]
+    );
+    assert_eq!(
+        ActixAdminFieldCondition::parse("note", "status in (\"a=b\", c)"),
+        ActixAdminFieldCondition {
+            field: "status".to_string(),
+            values: vec!["a=b".to_string(), "c".to_string()],
+        }
+    );
+
+    let view_model = ActixAdminViewModel::from(Comment);
+    let field = view_model
+        .fields
+        .iter()
+        .find(|f| f.field_name == "review_note")
+        .unwrap();
+    assert_eq!(
+        field.visible_when.as_ref().unwrap().values,
+        vec!["1".to_string(), "2".to_string()]
+    );
+    assert_eq!(field.required_when.as_ref().unwrap().field, "status");
+}
+
+#[actix_web::test]
+#[should_panic(expected = "invalid condition `status` of field `note`")]
+async fn malformed_rules_panic() {
+    ActixAdminFieldCondition::parse("note", "status");
+}
+
+#[actix_web::test]
+async fn hidden_fields_are_not_saved() {
+    let db = setup_db(false).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/comment/create_post_from_plaintext",
+        CommentForm::new("0", "ignored"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    let comment = Comment::find().one(&db).await.unwrap().unwrap();
+    assert_eq!(comment.review_note, None);
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/comment/create_post_from_plaintext",
+        CommentForm::new("1", "kept"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    let comment = Comment::find_by_id(2).one(&db).await.unwrap().unwrap();
+    assert_eq!(comment.review_note.as_deref(), Some("kept"));
+
+    // Hiding the field on edit clears the stored value.
+    let (status, body) = post_form(
+        &db,
+        "/admin/comment/edit_post_from_plaintext/2",
+        CommentForm::new("0", "kept"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    let comment = Comment::find_by_id(2).one(&db).await.unwrap().unwrap();
+    assert_eq!(comment.review_note, None);
+}
+
+#[actix_web::test]
+async fn required_when_rejects_empty_values() {
+    let db = setup_db(false).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/comment/create_post_from_plaintext",
+        CommentForm::new("2", " "),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Cannot be empty"), "{body}");
+    assert!(Comment::find().one(&db).await.unwrap().is_none());
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/comment/create_post_from_plaintext",
+        CommentForm::new("2", "spam"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+}
+
+#[actix_web::test]
+async fn forms_carry_the_rules() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/comment/create")).await;
+    assert!(
+        body.contains(
+            "data-visible-when=\"status\" data-visible-when-values=\"1,2\" hidden data-required-when=\"status\" data-required-when-values=\"2\">"
+        ),
+        "{body}"
+    );
+    assert!(body.contains("aaFieldConditions(document.currentScript.closest(\"form\"))"));
+
+    // Comment 1 is published: the note is shown but optional.
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/comment/edit/1")).await;
+    assert!(
+        body.contains("data-visible-when-values=\"1,2\" data-required-when"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<span class=\"aa-required-when\" hidden> *</span>"),
+        "{body}"
+    );
+}
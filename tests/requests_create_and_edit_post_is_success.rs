@@ -3,7 +3,6 @@ use test_setup::prelude::*;
 
 #[cfg(test)]
 mod post_create_and_edit_is_success {
-    use actix_admin::prelude::*;
     use actix_web::{http::header::ContentType, test, App};
     use chrono::{NaiveDate, NaiveDateTime};
     use sea_orm::{prelude::Decimal, EntityTrait, PaginatorTrait};
@@ -0,0 +1,70 @@
+//! Integration tests for the initial values of the create form.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+use test_setup::Comment;
+
+fn current_user(session: &Session) -> Option<String> {
+    session
+        .get::<String>("user")
+        .ok()
+        .flatten()
+        .or_else(|| Some("nobody@home.com".to_string()))
+}
+
+async fn get(db: &DatabaseConnection, uri: &str) -> String {
+    let mut builder = create_actix_admin_builder(false, None, false);
+    builder.add_field_default_for_entity::<Comment>("user", current_user);
+    let (status, body) = call_admin(builder, db, test::TestRequest::get().uri(uri)).await;
+    assert!(status.is_success(), "{uri}: {status}");
+    body
+}
+
+#[actix_web::test]
+async fn fields_start_with_their_defaults() {
+    let db = setup_db(false).await;
+
+    let body = get(&db, "/admin/comment/create").await;
+    assert!(body.contains("value=\"100\" name=\"my_decimal\""), "{body}");
+    assert!(
+        body.contains("value=\"nobody@home.com\" name=\"user\""),
+        "{body}"
+    );
+    assert!(body.contains("value=\"\" name=\"comment\""), "{body}");
+}
+
+#[actix_web::test]
+async fn querystring_prefills_known_fields() {
+    let db = setup_db(true).await;
+
+    let body = get(
+        &db,
+        "/admin/comment/create?post_id=2&status=1&my_decimal=150&user=me%40home.com&bogus=1",
+    )
+    .await;
+    let flat = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    // The label of a prefilled foreign key is loaded.
+    assert!(
+        flat.contains("<option value=\"2\" selected> Test 2 </option>"),
+        "{body}"
+    );
+    assert!(flat.contains("selected value=\"1\">Approved"), "{body}");
+    assert!(body.contains("value=\"150\" name=\"my_decimal\""), "{body}");
+    assert!(
+        body.contains("value=\"me@home.com\" name=\"user\""),
+        "{body}"
+    );
+    assert!(!body.contains("bogus"), "{body}");
+}
+
+#[actix_web::test]
+#[should_panic(expected = "add_field_default_for_entity: entity `comment` has no field `author`")]
+async fn defaults_must_name_a_field() {
+    let mut builder = create_actix_admin_builder(false, None, false);
+    builder.add_field_default_for_entity::<Comment>("author", current_user);
+}
@@ -0,0 +1,138 @@
+//! Integration tests for the `DateRange` / `DateTimeRange` filter types.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use chrono::{Duration, Local};
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+use test_setup::{Comment, Post};
+
+async fn get_body(db: &DatabaseConnection, uri: &str) -> String {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    assert_eq!(status.as_u16(), 200);
+    body
+}
+
+async fn count<E: ActixAdminViewModelTrait>(
+    db: &DatabaseConnection,
+    view_model: &ActixAdminViewModel,
+    filters: &str,
+) -> usize {
+    let query = ListQuery::from_query(&format!("entities_per_page=1000&{filters}"), view_model);
+    let (_, entities) = E::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities.len()
+}
+
+#[actix_web::test]
+async fn date_range_filter_receives_the_parsed_range() {
+    let db = setup_db(true).await;
+    let post = ActixAdminViewModel::from(Post);
+    let today = Local::now().date_naive();
+    let tomorrow = today + Duration::days(1);
+
+    // Every post was inserted today.
+    assert_eq!(
+        count::<Post>(&db, &post, "filter_Inserted__preset=today").await,
+        999
+    );
+    assert_eq!(
+        count::<Post>(&db, &post, &format!("filter_Inserted__from={tomorrow}")).await,
+        0
+    );
+    // The upper bound includes the whole day.
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            &format!("filter_Inserted__from=2000-01-01&filter_Inserted__to={today}")
+        )
+        .await,
+        999
+    );
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_Inserted__from=2000-01-01&filter_Inserted__to=2000-12-31"
+        )
+        .await,
+        0
+    );
+    // Malformed bounds are ignored.
+    assert_eq!(
+        count::<Post>(&db, &post, "filter_Inserted__from=yesterday").await,
+        999
+    );
+}
+
+#[actix_web::test]
+async fn date_time_range_filter_compares_times() {
+    let db = setup_db(true).await;
+    let comment = ActixAdminViewModel::from(Comment);
+    let tomorrow = Local::now().date_naive() + Duration::days(1);
+
+    assert_eq!(
+        count::<Comment>(
+            &db,
+            &comment,
+            &format!("filter_Inserted__to={tomorrow}T00:00")
+        )
+        .await,
+        999
+    );
+    assert_eq!(
+        count::<Comment>(
+            &db,
+            &comment,
+            &format!("filter_Inserted__from={tomorrow}T00:00")
+        )
+        .await,
+        0
+    );
+}
+
+#[actix_web::test]
+async fn sidebar_renders_range_inputs_and_presets() {
+    let db = setup_db(false).await;
+
+    let body = get_body(
+        &db,
+        "/admin/post/list?filter_Inserted__from=2024-01-01&filter_Inserted__to=2024-01-31",
+    )
+    .await;
+    assert!(
+        body.contains(
+            "value=\"2024-01-01\" type=\"date\" placeholder=\"\" name=\"filter_Inserted__from\""
+        ),
+        "{body}"
+    );
+    assert!(
+        body.contains(
+            "value=\"2024-01-31\" type=\"date\" placeholder=\"\" name=\"filter_Inserted__to\""
+        ),
+        "{body}"
+    );
+    assert!(body.contains("name=\"filter_Inserted__preset\""), "{body}");
+
+    let body = get_body(
+        &db,
+        "/admin/comment/list?filter_Inserted__preset=last_7_days",
+    )
+    .await;
+    assert!(
+        body.contains(
+            "value=\"\" type=\"datetime-local\" placeholder=\"\" name=\"filter_Inserted__from\""
+        ),
+        "{body}"
+    );
+    assert!(
+        body.contains("<option value=\"last_7_days\" selected>Last 7 days</option>"),
+        "{body}"
+    );
+}
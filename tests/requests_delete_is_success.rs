@@ -3,7 +3,6 @@ use test_setup::prelude::*;
 
 #[cfg(test)]
 mod post_delete_is_success {
-    use actix_admin::prelude::*;
     use actix_web::{http::header::ContentType, test, App};
     use itertools::Itertools;
     use sea_orm::{
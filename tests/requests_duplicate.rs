@@ -0,0 +1,224 @@
+//! Integration tests for duplicating records.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::http::header::ContentType;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use sea_orm::{ActiveModelTrait, EntityTrait, Set};
+
+use test_setup::prelude::*;
+use test_setup::{post, Post};
+
+/// A `multipart/form-data` body with the given text fields and a file input
+/// holding `(filename, content)`. An empty filename and content is what
+/// browsers submit when no file is chosen.
+fn multipart_body(
+    boundary: &str,
+    fields: &[(&str, &str)],
+    file_field: &str,
+    (filename, content): (&str, &str),
+) -> String {
+    let mut body = String::new();
+    for (name, value) in fields {
+        body.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+    body.push_str(&format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"{file_field}\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n{content}\r\n"
+    ));
+    body.push_str(&format!("--{boundary}--\r\n"));
+    body
+}
+
+#[actix_web::test]
+async fn duplicate_model_skips_keys_files_and_non_copyable_fields() {
+    let mut view_model = ActixAdminViewModel::from(Post);
+    view_model.non_copyable_fields = vec!["external_id".to_string()];
+
+    let mut source = ActixAdminModel::create_empty();
+    source.primary_key = Some("7".to_string());
+    for (field, value) in [
+        ("title", "Source"),
+        ("cover_image", "cover.png"),
+        ("api_secret", "hash"),
+        ("external_id", "EXT-7"),
+    ] {
+        source.values.insert(field.to_string(), value.to_string());
+    }
+
+    let model = view_model.duplicate_model(&source);
+    assert_eq!(model.primary_key, None);
+    assert_eq!(
+        model.values.keys().collect::<Vec<_>>(),
+        vec![&"title".to_string()]
+    );
+}
+
+#[actix_web::test]
+async fn duplicate_form_is_prefilled_from_the_source() {
+    let db = setup_db(true).await;
+
+    let (status, body) = call(&db, test::TestRequest::get().uri("/admin/post/duplicate/1")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("value=\"Test 1\""), "{body}");
+    assert!(body.contains("https://example.com/1"), "{body}");
+    assert!(!body.contains("EXT-00001"), "{body}");
+
+    let (status, _) = call(
+        &db,
+        test::TestRequest::get().uri("/admin/post/duplicate/5000"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+async fn show_page_and_list_rows_link_to_duplicate() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/show/1")).await;
+    assert!(
+        body.contains("href=\"/admin/post/duplicate/1\">Duplicate</a>"),
+        "{body}"
+    );
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/list")).await;
+    assert!(body.contains("/admin/post/duplicate/1\""), "{body}");
+    assert!(body.contains("fa-clone"), "{body}");
+}
+
+#[actix_web::test]
+async fn duplicates_get_a_copy_of_the_uploaded_files() {
+    let db = setup_db(true).await;
+    let upload_dir = "./file_uploads/post";
+    let source_file = "duplicate_source_cover.png";
+    std::fs::write(format!("{upload_dir}/{source_file}"), b"cover").unwrap();
+    post::ActiveModel {
+        id: Set(3),
+        cover_image: Set(Some(source_file.to_string())),
+        ..Default::default()
+    }
+    .update(&db)
+    .await
+    .unwrap();
+
+    let boundary = "duplicateboundary";
+    let body = multipart_body(
+        boundary,
+        &[
+            ("title", "Copy of 3"),
+            ("text", "copied"),
+            ("tea_mandatory", "EverydayTea"),
+            ("insert_date", "2024-01-31"),
+        ],
+        "cover_image",
+        ("", ""),
+    );
+    let (status, body) = call(
+        &db,
+        test::TestRequest::post()
+            .uri("/admin/post/duplicate/3")
+            .insert_header(ContentType(
+                format!("multipart/form-data; boundary={boundary}")
+                    .parse()
+                    .unwrap(),
+            ))
+            .set_payload(body),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+
+    let copy = Post::find_by_id(1000).one(&db).await.unwrap().unwrap();
+    assert_eq!(copy.title, "Copy of 3");
+    let copied_file = copy.cover_image.unwrap();
+    assert_ne!(copied_file, source_file);
+    assert!(copied_file.ends_with(source_file));
+    assert_eq!(
+        std::fs::read(format!("{upload_dir}/{copied_file}")).unwrap(),
+        b"cover"
+    );
+
+    std::fs::remove_file(format!("{upload_dir}/{source_file}")).unwrap();
+    std::fs::remove_file(format!("{upload_dir}/{copied_file}")).unwrap();
+}
+
+/// The files of `upload_dir` whose name ends with `suffix`.
+fn files_ending_with(upload_dir: &str, suffix: &str) -> Vec<String> {
+    std::fs::read_dir(upload_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.ends_with(suffix))
+        .collect()
+}
+
+async fn post_multipart(
+    db: &sea_orm::DatabaseConnection,
+    uri: &str,
+    title: &str,
+    file: (&str, &str),
+) -> (StatusCode, String) {
+    let boundary = "rollbackboundary";
+    let body = multipart_body(
+        boundary,
+        &[
+            ("title", title),
+            ("text", "rolled back"),
+            ("tea_mandatory", "EverydayTea"),
+            ("insert_date", "2024-01-31"),
+        ],
+        "cover_image",
+        file,
+    );
+    call(
+        db,
+        test::TestRequest::post()
+            .uri(uri)
+            .insert_header(ContentType(
+                format!("multipart/form-data; boundary={boundary}")
+                    .parse()
+                    .unwrap(),
+            ))
+            .set_payload(body),
+    )
+    .await
+}
+
+#[actix_web::test]
+async fn a_rolled_back_duplicate_removes_the_copied_files() {
+    let db = setup_db(true).await;
+    let upload_dir = "./file_uploads/post";
+    let source_file = "rollback_source_cover.png";
+    std::fs::write(format!("{upload_dir}/{source_file}"), b"cover").unwrap();
+    post::ActiveModel {
+        id: Set(6),
+        cover_image: Set(Some(source_file.to_string())),
+        ..Default::default()
+    }
+    .update(&db)
+    .await
+    .unwrap();
+
+    // The after_create hook of the post fails for this title.
+    let (status, body) = post_multipart(&db, "/admin/post/duplicate/6", "rollback", ("", "")).await;
+    let files = files_ending_with(upload_dir, source_file);
+    std::fs::remove_file(format!("{upload_dir}/{source_file}")).unwrap();
+    assert!(!status.is_redirection());
+    assert!(body.contains("Rolled back by hook"), "{body}");
+    assert_eq!(files, vec![source_file.to_string()]);
+}
+
+#[actix_web::test]
+async fn a_rolled_back_create_removes_the_uploaded_files() {
+    let db = setup_db(false).await;
+    let upload_dir = "./file_uploads/post";
+    let upload = "rollback_upload_cover.png";
+
+    let (status, body) =
+        post_multipart(&db, "/admin/post/create", "rollback", (upload, "cover")).await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Rolled back by hook"), "{body}");
+    assert!(files_ending_with(upload_dir, upload).is_empty());
+}
@@ -0,0 +1,143 @@
+//! Integration tests for enum select lists whose stored values differ from
+//! their labels.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serde::Serialize;
+
+use test_setup::comment::Status;
+use test_setup::post::Tea;
+use test_setup::prelude::*;
+use test_setup::Comment;
+
+#[derive(Serialize)]
+struct CommentForm {
+    insert_date: &'static str,
+    comment: &'static str,
+    user: &'static str,
+    is_visible: &'static str,
+    my_decimal: &'static str,
+    status: &'static str,
+}
+
+async fn create(db: &DatabaseConnection, status: &'static str) -> bool {
+    let form = CommentForm {
+        insert_date: "1977-04-01T14:00",
+        comment: "enum",
+        user: "test",
+        is_visible: "true",
+        my_decimal: "200",
+        status,
+    };
+    let (status, _) = post_form(db, "/admin/comment/create_post_from_plaintext", form).await;
+    status.is_redirection()
+}
+
+async fn count(db: &DatabaseConnection, filters: &str) -> usize {
+    let view_model = ActixAdminViewModel::from(Comment);
+    let query = ListQuery::from_query(&format!("entities_per_page=1000&{filters}"), &view_model);
+    let (_, entities) = Comment::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities.len()
+}
+
+#[actix_web::test]
+async fn options_pair_stored_values_with_labels() {
+    let db = setup_db(false).await;
+
+    assert_eq!(
+        Status::get_key_value(&db, None).await.unwrap(),
+        vec![
+            ("0".to_string(), "Awaiting review".to_string()),
+            ("1".to_string(), "Approved".to_string()),
+            ("2".to_string(), "Rejected".to_string()),
+        ]
+    );
+    assert_eq!(Status::from_select_value("1"), Some(Status::Published));
+    assert_eq!(Status::from_select_value("Published"), None);
+    // Enums without labels keep their Display string for both.
+    assert_eq!(
+        Tea::get_key_value(&db, None).await.unwrap()[0],
+        ("EverydayTea".to_string(), "EverydayTea".to_string())
+    );
+}
+
+#[actix_web::test]
+async fn forms_submit_the_stored_value() {
+    let db = setup_db(false).await;
+
+    assert!(create(&db, "1").await);
+    let comment = Comment::find().one(&db).await.unwrap().unwrap();
+    assert_eq!(comment.status, Some(Status::Published));
+
+    assert!(create(&db, "").await);
+    let comment = Comment::find_by_id(2).one(&db).await.unwrap().unwrap();
+    assert_eq!(comment.status, None);
+
+    assert!(!create(&db, "Published").await);
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/comment/edit/1")).await;
+    assert!(
+        body.contains("<option  selected  value=\"1\">Approved</option>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("value=\"0\">Awaiting review</option>"),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn list_show_and_export_display_the_label() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(
+        &db,
+        test::TestRequest::get().uri("/admin/comment/list?entities_per_page=3"),
+    )
+    .await;
+    assert!(body.contains("Awaiting review"), "{body}");
+    assert!(body.contains("Approved"), "{body}");
+    // The sidebar filter offers the labels too.
+    assert!(
+        body.contains("<option value=\"0\">Awaiting review</option>"),
+        "{body}"
+    );
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/comment/show/3")).await;
+    assert!(body.contains("Awaiting review"), "{body}");
+
+    let (_, body) = call(
+        &db,
+        test::TestRequest::get().uri("/admin/comment/export_csv?entities_per_page=3"),
+    )
+    .await;
+    let rows: Vec<&str> = body.lines().collect();
+    assert!(rows[0].ends_with(",status"), "{body}");
+    assert!(rows.iter().any(|row| row.ends_with(",Approved")), "{body}");
+    assert!(
+        rows.iter().any(|row| row.ends_with(",Awaiting review")),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn filters_match_the_stored_value() {
+    let db = setup_db(true).await;
+
+    assert_eq!(count(&db, "filter_status=1").await, 333);
+    assert_eq!(count(&db, "filter_status=0&filter_status=2").await, 333);
+    assert_eq!(
+        count(&db, "filter_status=1&filter_status__op=not_equals").await,
+        333
+    );
+    assert_eq!(
+        count(&db, "filter_status=&filter_status__op=is_null").await,
+        333
+    );
+}
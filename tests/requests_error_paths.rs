@@ -11,7 +11,6 @@ use test_setup::prelude::*;
 #[cfg(test)]
 mod error_paths {
     use super::create_app;
-    use actix_admin::prelude::*;
     use actix_web::test;
     use actix_web::App;
 
@@ -29,6 +28,19 @@ mod error_paths {
         assert_eq!(resp.status().as_u16(), 400);
     }
 
+    /// Every column of a multi-column sort is validated, not just the first.
+    #[actix_web::test]
+    async fn unknown_column_in_multi_sort_returns_400() {
+        let db = super::setup_db(false).await;
+        let app = create_app!(&db, false, None, false);
+
+        let req = test::TestRequest::get()
+            .uri("/admin/post/list?sort=title,-totally_not_a_column")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 400);
+    }
+
     /// GET /show/{id} for a missing entity should be 404, not 500.
     #[actix_web::test]
     async fn show_missing_entity_returns_404() {
@@ -69,6 +81,24 @@ mod error_paths {
         assert_eq!(resp.status().as_u16(), 400);
     }
 
+    /// DELETE /file/{id}/{column} answers with the emptied form input. It
+    /// used to render the nonexistent `form_elements/input.html` and fail
+    /// with 500 after the file had been cleared.
+    #[actix_web::test]
+    async fn delete_file_renders_the_emptied_input() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(&db, false, None, false);
+
+        let req = test::TestRequest::delete()
+            .uri("/admin/post/file/3/cover_image")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 200);
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        assert!(body.contains(r#"type="file""#), "{body}");
+        assert!(body.contains(r#"name="cover_image""#), "{body}");
+    }
+
     /// GET /file/{id}/{column} where `column` is not a FileUpload field must
     /// return 400 (previously silently attempted a file lookup).
     #[actix_web::test]
@@ -103,6 +133,20 @@ mod error_paths {
         );
     }
 
+    /// Bulk delete of a missing entity is 404, like the single delete.
+    #[actix_web::test]
+    async fn delete_many_missing_entity_returns_404() {
+        let db = super::setup_db(true).await;
+        let app = create_app!(&db, false, None, false);
+
+        let req = test::TestRequest::delete()
+            .uri("/admin/post/delete")
+            .set_form([("ids", "1"), ("ids", "99999")])
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status().as_u16(), 404);
+    }
+
     /// Garbage in the querystring must not panic; the route should ignore
     /// unknown/unparseable params and render normally.
     #[actix_web::test]
@@ -0,0 +1,175 @@
+//! Integration tests for the declarative validation attributes.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::EntityTrait;
+use serde::Serialize;
+
+use test_setup::prelude::*;
+use test_setup::{Comment, Post};
+
+#[derive(Serialize)]
+struct CommentForm {
+    insert_date: &'static str,
+    comment: &'static str,
+    user: &'static str,
+    my_decimal: &'static str,
+    priority: &'static str,
+    views: &'static str,
+}
+
+impl Default for CommentForm {
+    fn default() -> Self {
+        CommentForm {
+            insert_date: "1977-04-01T14:00",
+            comment: "constrained",
+            user: "test",
+            my_decimal: "200",
+            priority: "1",
+            views: "100",
+        }
+    }
+}
+
+fn contact_post() -> PostForm {
+    PostForm {
+        homepage: Some("https://example.com"),
+        contact_email: Some("me@example.com"),
+        ..PostForm::new("constrained")
+    }
+}
+
+#[actix_web::test]
+async fn attributes_are_carried_by_the_fields() {
+    let view_model = ActixAdminViewModel::from(Comment);
+    let field = |name: &str| {
+        view_model
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .unwrap()
+    };
+
+    assert_eq!(field("comment").regex.as_deref(), Some("[^<>]*"));
+    assert!(field("comment").compiled_regex.is_some());
+    assert_eq!(field("views").max.as_deref(), Some("4000000000"));
+    assert_eq!(
+        field("priority").one_of,
+        vec!["-3", "-2", "-1", "0", "1", "2", "3", "4"]
+    );
+
+    let view_model = ActixAdminViewModel::from(Post);
+    let title = view_model
+        .fields
+        .iter()
+        .find(|f| f.field_name == "title")
+        .unwrap();
+    assert_eq!((title.min_length, title.max_length), (Some(2), Some(100)));
+}
+
+#[actix_web::test]
+async fn violations_are_reported_per_field() {
+    let db = setup_db(false).await;
+    let uri = "/admin/comment/create_post_from_plaintext";
+
+    for (form, error) in [
+        (
+            CommentForm {
+                comment: "<b>bold</b>",
+                ..Default::default()
+            },
+            "Invalid format",
+        ),
+        (
+            CommentForm {
+                views: "4000000001",
+                ..Default::default()
+            },
+            "Must be at most 4000000000",
+        ),
+        (
+            CommentForm {
+                priority: "7",
+                ..Default::default()
+            },
+            "Must be one of: -3, -2, -1, 0, 1, 2, 3, 4",
+        ),
+        (
+            CommentForm {
+                insert_date: "1969-12-31T23:59",
+                ..Default::default()
+            },
+            "Must be at least 1970-01-01 00:00:00",
+        ),
+    ] {
+        let (status, body) = post_form(&db, uri, form).await;
+        assert!(!status.is_redirection());
+        assert!(body.contains(error), "{error}: {body}");
+    }
+    assert!(Comment::find().one(&db).await.unwrap().is_none());
+
+    let (status, body) = post_form(&db, uri, CommentForm::default()).await;
+    assert!(status.is_redirection(), "{body}");
+}
+
+#[actix_web::test]
+async fn lengths_emails_and_urls_are_checked() {
+    let db = setup_db(false).await;
+    let uri = "/admin/post/create_post_from_plaintext";
+
+    for (form, error) in [
+        (
+            PostForm {
+                title: "a",
+                ..contact_post()
+            },
+            "Must be at least 2 characters",
+        ),
+        (
+            PostForm {
+                contact_email: Some("me@example"),
+                ..contact_post()
+            },
+            "Invalid email address",
+        ),
+        (
+            PostForm {
+                homepage: Some("example.com"),
+                ..contact_post()
+            },
+            "Invalid URL",
+        ),
+    ] {
+        let (status, body) = post_form(&db, uri, form).await;
+        assert!(!status.is_redirection());
+        assert!(body.contains(error), "{error}: {body}");
+    }
+
+    // Optional fields may still be left empty.
+    let (status, body) = post_form(
+        &db,
+        uri,
+        PostForm {
+            homepage: Some(""),
+            contact_email: Some(""),
+            ..contact_post()
+        },
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+}
+
+#[actix_web::test]
+async fn inputs_carry_html5_attributes() {
+    let db = setup_db(false).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/create")).await;
+    assert!(body.contains("minlength=\"2\" maxlength=\"100\""), "{body}");
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/comment/create")).await;
+    assert!(body.contains("pattern=\"[^&lt;&gt;]*\""), "{body}");
+    assert!(body.contains("min=\"1970-01-01T00:00:00\""), "{body}");
+    assert!(body.contains("max=\"4000000000\""), "{body}");
+}
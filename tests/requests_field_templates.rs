@@ -0,0 +1,90 @@
+//! Integration tests for per-field custom render templates.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+use test_setup::Comment;
+
+async fn get(db: &DatabaseConnection, uri: &str) -> String {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    assert!(status.is_success(), "{uri}: {status}");
+    body
+}
+
+#[actix_web::test]
+async fn attributes_name_the_partials() {
+    let view_model = ActixAdminViewModel::from(Comment);
+    let field = |name: &str| {
+        view_model
+            .fields
+            .iter()
+            .find(|f| f.field_name == name)
+            .unwrap()
+    };
+
+    let priority = field("priority");
+    assert_eq!(
+        priority.list_template.as_deref(),
+        Some("comment/priority.html")
+    );
+    assert_eq!(priority.show_template, None);
+    assert_eq!(
+        priority.form_template.as_deref(),
+        Some("comment/priority_input.html")
+    );
+    assert_eq!(
+        field("post_id").show_template.as_deref(),
+        Some("comment/post_link.html")
+    );
+    assert_eq!(field("comment").list_template, None);
+}
+
+#[actix_web::test]
+async fn list_cells_use_the_list_template() {
+    let db = setup_db(true).await;
+
+    let body = get(&db, "/admin/comment/list?entities_per_page=3").await;
+    assert!(
+        body.contains("<td><span class=\"tag priority-1\">P1</span></td>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<td><span class=\"tag priority-3\">P3</span></td>"),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn show_page_uses_the_show_template_with_fk_values() {
+    let db = setup_db(true).await;
+
+    let body = get(&db, "/admin/comment/show/2").await;
+    assert!(
+        body.contains("<a href=\"/admin/post/show/2\">Test 2</a>"),
+        "{body}"
+    );
+    // Without a show_template the built-in rendering is kept.
+    assert!(!body.contains("priority-2"), "{body}");
+}
+
+#[actix_web::test]
+async fn forms_use_the_form_template() {
+    let db = setup_db(true).await;
+
+    let body = get(&db, "/admin/comment/edit/4").await;
+    assert!(
+        body.contains("<input type=\"range\" min=\"0\" max=\"4\" name=\"priority\" value=\"4\">"),
+        "{body}"
+    );
+    assert!(!body.contains("id=\"priority\""), "{body}");
+
+    let body = get(&db, "/admin/comment/create").await;
+    assert!(
+        body.contains("<input type=\"range\" min=\"0\" max=\"4\" name=\"priority\" value=\"\">"),
+        "{body}"
+    );
+}
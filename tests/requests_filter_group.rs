@@ -0,0 +1,120 @@
+//! Integration tests for advanced AND / OR filter groups.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+use test_setup::Post;
+
+async fn get(db: &DatabaseConnection, uri: &str) -> (u16, String) {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    (status.as_u16(), body)
+}
+
+fn list_uri(filter_group: &str) -> String {
+    format!(
+        "/admin/post/list?entities_per_page=100&where={}",
+        urlencoding::encode(filter_group)
+    )
+}
+
+async fn list_ids(db: &DatabaseConnection, filter_group: &str) -> Vec<String> {
+    let view_model = ActixAdminViewModel::from(Post);
+    let qs = format!(
+        "entities_per_page=100&where={}",
+        urlencoding::encode(filter_group)
+    );
+    let query = ListQuery::from_query(&qs, &view_model);
+    let (_, entities) = Post::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities
+        .into_iter()
+        .map(|e| e.primary_key.unwrap())
+        .collect()
+}
+
+#[actix_web::test]
+async fn nested_groups_combine_with_and_and_or() {
+    let db = setup_db(true).await;
+
+    let ids = list_ids(
+        &db,
+        r#"{"combinator":"and","rules":[
+            {"field":"title","operator":"contains","value":"Test"},
+            {"combinator":"or","rules":[
+                {"field":"id","operator":"lt","value":"4"},
+                {"field":"id","operator":"greater_than","value":"997"}
+            ]}
+        ]}"#,
+    )
+    .await;
+    assert_eq!(ids, vec!["1", "2", "3", "998", "999"]);
+
+    let ids = list_ids(
+        &db,
+        r#"{"combinator":"or","rules":[
+            {"field":"title","operator":"equals","value":"Test 15"},
+            {"combinator":"and","rules":[
+                {"field":"id","operator":"in","value":"20,21,22"},
+                {"field":"title","operator":"not_equals","value":"Test 21"}
+            ]},
+            {"combinator":"and","rules":[]}
+        ]}"#,
+    )
+    .await;
+    assert_eq!(ids, vec!["15", "20", "22"]);
+
+    // An empty group does not filter.
+    let ids = list_ids(&db, r#"{"combinator":"or","rules":[]}"#).await;
+    assert_eq!(ids.len(), 100);
+}
+
+#[actix_web::test]
+async fn list_applies_the_where_parameter() {
+    let db = setup_db(true).await;
+
+    let (status, body) = get(
+        &db,
+        &list_uri(r#"{"combinator":"or","rules":[{"field":"id","operator":"equals","value":"7"},{"field":"id","operator":"equals","value":"9"}]}"#),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert!(body.contains("/admin/post/show/7\""), "{body}");
+    assert!(body.contains("/admin/post/show/9\""), "{body}");
+    assert!(!body.contains("/admin/post/show/8\""), "{body}");
+    // The group is echoed back into the query builder.
+    assert!(body.contains("id=\"query_builder\""), "{body}");
+    assert!(
+        body.contains("&quot;combinator&quot;:&quot;or&quot;"),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn invalid_groups_are_reported() {
+    let db = setup_db(false).await;
+
+    let (status, body) = get(
+        &db,
+        &list_uri(r#"{"rules":[{"field":"secret","operator":"equals","value":"1"}]}"#),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert!(body.contains("Unknown filter field `secret`"), "{body}");
+
+    let (status, body) = get(&db, &list_uri("{not json")).await;
+    assert_eq!(status, 200);
+    assert!(body.contains("Invalid advanced filter"), "{body}");
+
+    let (status, _) = get(
+        &db,
+        &format!("/admin/post/export_csv?where={}", urlencoding::encode("[")),
+    )
+    .await;
+    assert_eq!(status, 400);
+}
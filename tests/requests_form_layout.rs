@@ -0,0 +1,102 @@
+//! Integration tests for tabs, fieldsets and rows on the form and show pages.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+
+use test_setup::prelude::*;
+use test_setup::Post;
+
+/// The line of the tab bar linking to the tab at `index`.
+fn tab_link(body: &str, index: usize) -> &str {
+    let onclick = format!("showFormTab(this, {index})");
+    body.lines()
+        .find(|line| line.contains(&onclick))
+        .unwrap_or("")
+}
+
+#[actix_web::test]
+async fn fields_are_grouped_by_tab_fieldset_and_row() {
+    let mut view_model = ActixAdminViewModel::from(Post);
+    view_model.collapsible_fieldsets = vec!["Advanced".to_string()];
+    let layout = view_model.form_layout(&ActixAdminModel::create_empty());
+
+    let tab_names: Vec<&str> = layout.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(tab_names, ["", "Details"]);
+    assert!(layout[0].active);
+    assert!(!layout[1].active);
+
+    let details = &layout[1];
+    let fieldsets: Vec<(&str, bool)> = details
+        .fieldsets
+        .iter()
+        .map(|f| (f.name.as_str(), f.collapsible))
+        .collect();
+    assert_eq!(
+        fieldsets,
+        [("Contact", false), ("Media", false), ("Advanced", true)]
+    );
+    let contact_row: Vec<&str> = details.fieldsets[0].rows[0]
+        .iter()
+        .map(|f| f.field_name.as_str())
+        .collect();
+    assert_eq!(contact_row, ["homepage", "contact_email"]);
+
+    // Fields without a form_row each get a row of their own.
+    let general = &layout[0].fieldsets[0];
+    assert_eq!(general.name, "");
+    assert!(general.rows.iter().all(|row| row.len() == 1));
+    assert_eq!(general.rows[0][0].field_name, "title");
+}
+
+#[actix_web::test]
+async fn create_form_renders_tabs_fieldsets_and_columns() {
+    let db = setup_db(false).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/create")).await;
+    assert!(tab_link(&body, 0).contains(">General</a>"), "{body}");
+    assert!(tab_link(&body, 1).contains(">Details</a>"), "{body}");
+    assert!(tab_link(&body, 0).contains("active\""), "{body}");
+    assert!(body.contains(">Contact</legend>"), "{body}");
+    assert!(body.contains(">Advanced</summary>"), "{body}");
+    assert!(!body.contains(" open>\n        <summary"), "{body}");
+
+    let details = body.find("<div class=\"form-tab\" hidden>").expect(&body);
+    let title = body.find("name=\"title\"").expect(&body);
+    let homepage = body.find("name=\"homepage\"").expect(&body);
+    assert!(title < details && details < homepage, "{body}");
+    let columns = &body[details..homepage];
+    assert!(columns.contains("<div class=\"col"), "{body}");
+}
+
+#[actix_web::test]
+async fn tab_with_the_first_error_is_shown() {
+    let db = setup_db(false).await;
+
+    let (_, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        PostForm {
+            settings: Some("{"),
+            ..PostForm::new("layout")
+        },
+    )
+    .await;
+    assert!(tab_link(&body, 1).contains("active\""), "{body}");
+    assert!(tab_link(&body, 1).contains("Details<i"), "{body}");
+    let general = body.find("<div class=\"form-tab\" hidden>").expect(&body);
+    assert!(general < body.find("name=\"title\"").unwrap(), "{body}");
+    // A collapsible fieldset holding an error is expanded.
+    assert!(body.contains(" open>\n        <summary"), "{body}");
+}
+
+#[actix_web::test]
+async fn show_page_uses_the_layout() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/show/1")).await;
+    assert!(tab_link(&body, 1).contains(">Details</a>"), "{body}");
+    assert!(body.contains(">Media</legend>"), "{body}");
+    assert!(body.contains("<div class=\"form-tab\" hidden>"), "{body}");
+}
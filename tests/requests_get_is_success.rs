@@ -166,6 +166,29 @@ mod get_request_is_success {
         test_get_is_success(url.as_str(), &db).await
     }
 
+    #[actix_web::test]
+    async fn get_post_list_multi_column_sort() {
+        let db = super::setup_db(true).await;
+        let page_size = 10;
+        let url = format!(
+            "/admin/{}/list?sort=tea_mandatory,-id&entities_per_page={}",
+            crate::Post::get_entity_name(),
+            page_size
+        );
+
+        let entities = crate::Post::find()
+            .order_by_asc(crate::post::Column::TeaMandatory)
+            .order_by_desc(crate::post::Column::Id)
+            .paginate(&db, page_size)
+            .fetch_page(0)
+            .await
+            .unwrap();
+
+        let mut verify_titles: Vec<String> = entities.iter().map(|e| e.title.to_string()).collect();
+        verify_titles.push(r#"name="sort" value="tea_mandatory,-id""#.to_string());
+        test_response_contains(url.as_str(), &db, verify_titles).await
+    }
+
     async fn test_response_contains(
         url: &str,
         db: &DatabaseConnection,
@@ -0,0 +1,87 @@
+//! Integration tests for the navbar search across all entities.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+
+async fn get_body(db: &DatabaseConnection, builder: ActixAdminBuilder, uri: &str) -> String {
+    let (status, body) = call_admin(builder, db, test::TestRequest::get().uri(uri)).await;
+    assert_eq!(status.as_u16(), 200);
+    body
+}
+
+fn deny(_session: &Session) -> bool {
+    false
+}
+
+fn tenant_one(_session: &Session) -> Option<i32> {
+    Some(1)
+}
+
+#[actix_web::test]
+async fn results_are_grouped_by_entity_and_link_to_show_page() {
+    let db = setup_db(true).await;
+    let builder = create_actix_admin_builder(false, None, false);
+
+    let body = get_body(&db, builder, "/admin/search?q=Test+15").await;
+    assert!(body.contains("/admin/post/show/15\""), "{body}");
+    assert!(body.contains("/admin/comment/show/15\""), "{body}");
+    // Posts render with their display name (the title).
+    assert!(body.contains(">Test 15</a>"), "{body}");
+    // 11 posts match but only the first few are listed.
+    assert!(!body.contains("/admin/post/show/159\""), "{body}");
+    assert!(
+        body.contains("/admin/post/list?search=Test%2015\""),
+        "{body}"
+    );
+    assert!(
+        !body.contains("/admin/sample_with_tenant_id/show/"),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn entities_the_user_cannot_view_are_skipped() {
+    let db = setup_db(true).await;
+    let mut builder = create_actix_admin_builder(false, None, false);
+    builder
+        .actix_admin
+        .view_models
+        .get_mut(&Post::get_entity_name())
+        .unwrap()
+        .user_can_view_details = Some(deny);
+
+    let body = get_body(&db, builder, "/admin/search?q=Test+15").await;
+    assert!(!body.contains("/admin/post/show/"), "{body}");
+    assert!(body.contains("/admin/comment/show/15\""), "{body}");
+}
+
+#[actix_web::test]
+async fn results_are_scoped_to_the_tenant() {
+    let db = setup_db(true).await;
+    let builder = create_actix_admin_builder(false, Some(tenant_one), false);
+
+    let body = get_body(&db, builder, "/admin/search?q=TestTenant").await;
+    assert!(body.contains(">TestTenant1</a>"), "{body}");
+    assert!(!body.contains(">TestTenant0</a>"), "{body}");
+}
+
+#[actix_web::test]
+async fn navbar_renders_search_input() {
+    let db = setup_db(false).await;
+
+    let builder = create_actix_admin_builder(false, None, false);
+    let body = get_body(&db, builder, "/admin/").await;
+    assert!(body.contains("action=\"/admin/search\""), "{body}");
+    assert!(body.contains("id=\"global_search\""), "{body}");
+
+    // The term is echoed back into the input, and nothing matches.
+    let builder = create_actix_admin_builder(false, None, false);
+    let body = get_body(&db, builder, "/admin/search?q=nothing").await;
+    assert!(body.contains("value=\"nothing\""), "{body}");
+    assert!(body.contains("No results found."), "{body}");
+}
@@ -0,0 +1,260 @@
+//! Integration tests for `ActixAdminModelHooksTrait` and the transaction
+//! each write runs in, see the hooks of `test_setup::post`.
+
+mod test_setup;
+
+use actix_web::http::header::ContentType;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+
+use test_setup::post::{self, HOOK_CALLS};
+use test_setup::prelude::*;
+use test_setup::Post;
+
+async fn find_by_title(db: &DatabaseConnection, title: &str) -> Option<post::Model> {
+    Post::find()
+        .filter(post::Column::Title.eq(title))
+        .one(db)
+        .await
+        .unwrap()
+}
+
+fn hook_called(call: &str) -> bool {
+    HOOK_CALLS.lock().unwrap().iter().any(|c| c == call)
+}
+
+#[actix_web::test]
+async fn creates_and_edits_run_the_hooks() {
+    let db = setup_db(false).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        PostForm::new("shout:created"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    let created = find_by_title(&db, "CREATED").await.unwrap();
+    assert!(hook_called("before_create  shout:created"));
+    assert!(hook_called(&format!("after_create {} CREATED", created.id)));
+
+    let (status, body) = post_form(
+        &db,
+        &format!("/admin/post/edit_post_from_plaintext/{}", created.id),
+        PostForm::new("shout:edited"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    assert!(find_by_title(&db, "EDITED").await.is_some());
+    assert!(hook_called(&format!(
+        "before_update {} CREATED",
+        created.id
+    )));
+    assert!(hook_called(&format!("after_update {} EDITED", created.id)));
+}
+
+#[actix_web::test]
+async fn before_hooks_veto_creates_and_edits() {
+    let db = setup_db(true).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        PostForm::new("vetoed"),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Vetoed by hook"), "{body}");
+    assert!(find_by_title(&db, "vetoed").await.is_none());
+
+    let title = Post::find_by_id(1).one(&db).await.unwrap().unwrap().title;
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/edit_post_from_plaintext/1",
+        PostForm::new("vetoed"),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Vetoed by hook"), "{body}");
+    assert_eq!(
+        Post::find_by_id(1).one(&db).await.unwrap().unwrap().title,
+        title
+    );
+}
+
+#[actix_web::test]
+async fn deletes_run_the_hooks() {
+    let db = setup_db(false).await;
+    for title in ["protected", "deleted", "deleted many"] {
+        let (status, body) = post_form(
+            &db,
+            "/admin/post/create_post_from_plaintext",
+            PostForm::new(title),
+        )
+        .await;
+        assert!(status.is_redirection(), "{body}");
+    }
+    let protected = find_by_title(&db, "protected").await.unwrap().id;
+    let deleted = find_by_title(&db, "deleted").await.unwrap().id;
+    let deleted_many = find_by_title(&db, "deleted many").await.unwrap().id;
+
+    let (status, _) = call(
+        &db,
+        test::TestRequest::delete().uri(&format!("/admin/post/delete/{protected}")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(find_by_title(&db, "protected").await.is_some());
+
+    let (status, _) = call(
+        &db,
+        test::TestRequest::delete().uri(&format!("/admin/post/delete/{deleted}")),
+    )
+    .await;
+    assert!(status.is_success());
+    assert!(hook_called(&format!("before_delete {deleted} deleted")));
+    assert!(hook_called(&format!("after_delete {deleted} deleted")));
+
+    // A veto of one of the posts deletes none of them.
+    let delete_many = |ids: String| {
+        test::TestRequest::delete()
+            .uri("/admin/post/delete")
+            .insert_header(ContentType::form_url_encoded())
+            .set_payload(ids)
+    };
+    let (status, _) = call(
+        &db,
+        delete_many(format!("ids={protected}&ids={deleted_many}")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(find_by_title(&db, "deleted many").await.is_some());
+
+    let (status, _) = call(&db, delete_many(format!("ids={deleted_many}"))).await;
+    assert!(status.is_redirection());
+    assert!(find_by_title(&db, "deleted many").await.is_none());
+    assert!(hook_called(&format!(
+        "after_delete {deleted_many} deleted many"
+    )));
+}
+
+#[actix_web::test]
+async fn deleting_a_file_runs_the_update_hooks() {
+    let db = setup_db(true).await;
+    let before = Post::find_by_id(3).one(&db).await.unwrap().unwrap();
+    assert!(before.cover_image.is_some());
+
+    let (status, body) = call(
+        &db,
+        test::TestRequest::delete().uri("/admin/post/file/3/cover_image"),
+    )
+    .await;
+    assert!(status.is_success(), "{status} {body}");
+    let after = Post::find_by_id(3).one(&db).await.unwrap().unwrap();
+    assert_eq!(after.cover_image, None);
+    assert!(hook_called(&format!("before_update 3 {}", before.title)));
+    assert!(hook_called(&format!("after_update 3 {}", before.title)));
+}
+
+#[actix_web::test]
+async fn a_failed_edit_keeps_the_deleted_file() {
+    let db = setup_db(true).await;
+    let file_name = "failed_edit_keeps_file.png";
+    let file_path = format!("./file_uploads/post/{file_name}");
+    std::fs::create_dir_all("./file_uploads/post").unwrap();
+    std::fs::write(&file_path, b"png").unwrap();
+    // after_update fails for this title, after the column is cleared.
+    Post::update_many()
+        .col_expr(post::Column::Title, Expr::value("rollback"))
+        .col_expr(post::Column::CoverImage, Expr::value(file_name))
+        .filter(post::Column::Id.eq(6))
+        .exec(&db)
+        .await
+        .unwrap();
+
+    let (status, body) = call(
+        &db,
+        test::TestRequest::delete().uri("/admin/post/file/6/cover_image"),
+    )
+    .await;
+    let kept = std::path::Path::new(&file_path).exists();
+    std::fs::remove_file(&file_path).ok();
+    assert!(!status.is_success(), "{status} {body}");
+    assert!(kept);
+    let after = Post::find_by_id(6).one(&db).await.unwrap().unwrap();
+    assert_eq!(after.cover_image.as_deref(), Some(file_name));
+}
+
+#[actix_web::test]
+async fn failing_after_hooks_roll_back_creates_and_edits() {
+    let db = setup_db(false).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        PostForm::new("rollback"),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Rolled back by hook"), "{body}");
+    assert!(hook_called("before_create  rollback"));
+    assert!(find_by_title(&db, "rollback").await.is_none());
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        PostForm::new("kept"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    let kept = find_by_title(&db, "kept").await.unwrap().id;
+
+    let (status, body) = post_form(
+        &db,
+        &format!("/admin/post/edit_post_from_plaintext/{kept}"),
+        PostForm::new("rollback"),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Rolled back by hook"), "{body}");
+    assert!(hook_called(&format!("after_update {kept} rollback")));
+    assert!(find_by_title(&db, "kept").await.is_some());
+}
+
+#[actix_web::test]
+async fn failing_after_delete_rolls_back_the_whole_bulk_delete() {
+    let db = setup_db(false).await;
+    for title in ["first", "second"] {
+        let (status, body) = post_form(
+            &db,
+            "/admin/post/create_post_from_plaintext",
+            PostForm::new(title),
+        )
+        .await;
+        assert!(status.is_redirection(), "{body}");
+    }
+    let first = find_by_title(&db, "first").await.unwrap().id;
+    let second = find_by_title(&db, "second").await.unwrap().id;
+    // The hooks refuse to create it, so rename the post behind their back.
+    Post::update_many()
+        .col_expr(post::Column::Title, Expr::value("rollback"))
+        .filter(post::Column::Id.eq(second))
+        .exec(&db)
+        .await
+        .unwrap();
+
+    let (status, _) = call(
+        &db,
+        test::TestRequest::delete()
+            .uri("/admin/post/delete")
+            .insert_header(ContentType::form_url_encoded())
+            .set_payload(format!("ids={first}&ids={second}")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(hook_called(&format!("after_delete {second} rollback")));
+    assert!(find_by_title(&db, "first").await.is_some());
+    assert!(find_by_title(&db, "rollback").await.is_some());
+}
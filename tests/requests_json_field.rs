@@ -0,0 +1,131 @@
+//! Integration tests for `Json` fields and their JSON Schema validation.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::{DatabaseConnection, EntityTrait};
+use serde_json::json;
+
+use test_setup::prelude::*;
+use test_setup::Post;
+
+fn with_settings(settings: &'static str) -> PostForm {
+    PostForm {
+        settings: Some(settings),
+        ..PostForm::new("json")
+    }
+}
+
+async fn create(db: &DatabaseConnection, form: PostForm) -> (bool, String) {
+    let (status, body) = post_form(db, "/admin/post/create_post_from_plaintext", form).await;
+    (status.is_redirection(), body)
+}
+
+#[actix_web::test]
+async fn json_fields_are_detected_with_their_schema() {
+    let view_model = ActixAdminViewModel::from(Post);
+    let field = view_model
+        .fields
+        .iter()
+        .find(|f| f.field_name == "settings")
+        .unwrap();
+
+    assert_eq!(field.field_type, ActixAdminViewModelFieldType::Json);
+    assert_eq!(
+        field.json_schema.as_ref().unwrap()["required"],
+        json!(["theme"])
+    );
+}
+
+#[actix_web::test]
+async fn valid_json_is_stored_as_a_value() {
+    let db = setup_db(false).await;
+
+    let (created, _) = create(
+        &db,
+        with_settings("{\n  \"theme\": \"dark\",\n  \"retries\": 2\n}"),
+    )
+    .await;
+    assert!(created);
+    let post = Post::find().one(&db).await.unwrap().unwrap();
+    assert_eq!(
+        post.settings,
+        Some(json!({ "theme": "dark", "retries": 2 }))
+    );
+
+    let (created, _) = create(&db, with_settings("")).await;
+    assert!(created);
+    let post = Post::find_by_id(2).one(&db).await.unwrap().unwrap();
+    assert_eq!(post.settings, None);
+}
+
+#[actix_web::test]
+async fn unparsable_json_is_rejected() {
+    let db = setup_db(false).await;
+
+    let (created, body) = create(&db, with_settings("{\"theme\": ")).await;
+    assert!(!created);
+    assert!(body.contains("Invalid JSON: EOF while parsing"), "{body}");
+    assert!(Post::find().one(&db).await.unwrap().is_none());
+}
+
+#[actix_web::test]
+async fn schema_violations_are_reported_per_path() {
+    let mut model = ActixAdminModel::create_empty();
+    for (key, value) in [
+        ("title", "json"),
+        ("text", "json"),
+        ("tea_mandatory", "EverydayTea"),
+        ("insert_date", "2024-01-31"),
+        ("settings", r#"{"retries": -1, "tags": ["a", 1]}"#),
+    ] {
+        model.values.insert(key.to_string(), value.to_string());
+    }
+    test_setup::post::Entity::validate_model(&mut model);
+
+    assert_eq!(model.errors.get("settings/theme").unwrap(), "is required");
+    assert_eq!(
+        model.errors.get("settings/retries").unwrap(),
+        "must be at least 0"
+    );
+    assert_eq!(
+        model.errors.get("settings/tags/1").unwrap(),
+        "must be of type string"
+    );
+    assert!(model.errors.contains_key("settings"));
+
+    let db = setup_db(false).await;
+    let (created, body) = create(&db, with_settings(r#"{"theme": "blue"}"#)).await;
+    assert!(!created);
+    assert!(
+        body.contains("/theme: must be one of &quot;light&quot;, &quot;dark&quot;"),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn editor_and_tree_render_the_document() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/edit/1")).await;
+    assert!(body.contains("class=\"aa-json-editor\""), "{body}");
+    assert!(body.contains("id=\"aa-json-settings\""), "{body}");
+    // Pretty-printed, then HTML-escaped by the template.
+    assert!(
+        body.contains("{\n  &quot;theme&quot;: &quot;light&quot;,\n  &quot;retries&quot;: 1,"),
+        "{body}"
+    );
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/show/1")).await;
+    assert!(
+        body.contains("<div class=\"aa-json-tree\"><details open>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<span class=\"aa-json-key\">theme</span>: <span class=\"aa-json-string\">&quot;light&quot;</span>"),
+        "{body}"
+    );
+    assert!(body.contains("&quot;&lt;b&gt;&quot;"), "{body}");
+    assert!(!body.contains("\"<b>\""), "{body}");
+}
@@ -0,0 +1,250 @@
+//! Integration tests for opt-in keyset (cursor) pagination.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::{
+    DatabaseConnection, DbBackend, EntityTrait, QueryFilter, QueryOrder, QueryTrait, Value,
+};
+
+use test_setup::prelude::*;
+use test_setup::{post, Post, SampleWithTenantId};
+
+fn keyset_params(sort: Vec<SortColumn>, cursor: ActixAdminCursor) -> ActixAdminViewModelParams {
+    ActixAdminViewModelParams {
+        page: None,
+        entities_per_page: Some(100),
+        viewmodel_filter: Vec::new(),
+        filter_group: None,
+        search: String::new(),
+        search_exclude: Vec::new(),
+        sort_by: sort[0].field.clone(),
+        sort_order: sort[0].order.clone(),
+        sort,
+        cursor: Some(cursor),
+        search_backend: ActixAdminSearchBackend::Like,
+        search_by_relevance: false,
+        computed_sort_exprs: Default::default(),
+        tenant_ref: None,
+    }
+}
+
+fn ids(entities: &[ActixAdminModel]) -> Vec<String> {
+    entities
+        .iter()
+        .map(|e| e.primary_key.clone().unwrap())
+        .collect()
+}
+
+async fn expected_ids(db: &DatabaseConnection) -> Vec<String> {
+    Post::find()
+        .order_by_desc(post::Column::TeaMandatory)
+        .order_by_asc(post::Column::Id)
+        .all(db)
+        .await
+        .unwrap()
+        .iter()
+        .map(|p| p.id.to_string())
+        .collect()
+}
+
+/// Every primary key of a keyset walk from the first page to the last.
+async fn walk_forward(db: &DatabaseConnection, sort: &[SortColumn]) -> Vec<String> {
+    let mut seen = Vec::new();
+    let mut cursor = ActixAdminCursor::First;
+    loop {
+        let (num_pages, entities) = Post::list(db, &keyset_params(sort.to_vec(), cursor))
+            .await
+            .unwrap();
+        assert!(num_pages.is_none(), "keyset mode must not count rows");
+        let has_more = entities.len() > 100;
+        let page = ids(&entities[..entities.len().min(100)]);
+        seen.extend(page.iter().cloned());
+        if !has_more {
+            return seen;
+        }
+        cursor = ActixAdminCursor::After(page.last().unwrap().clone());
+    }
+}
+
+/// Walking forward with `After` and back with `Before` visits every row
+/// exactly once, in the same order offset pagination would, even though
+/// every row shares the same sort value (ties broken on the primary key).
+#[actix_web::test]
+async fn keyset_walk_matches_offset_order() {
+    let db = setup_db(true).await;
+    let sort = vec![SortColumn::new("tea_mandatory", SortOrder::Desc)];
+    let expected = expected_ids(&db).await;
+
+    assert_eq!(walk_forward(&db, &sort).await, expected);
+
+    // Step back one page from the start of the final page.
+    let last_page_start = expected[900].clone();
+    let (_, entities) = Post::list(
+        &db,
+        &keyset_params(sort, ActixAdminCursor::Before(last_page_start)),
+    )
+    .await
+    .unwrap();
+    assert_eq!(entities.len(), 101);
+    assert_eq!(ids(&entities[1..]), expected[800..900].to_vec());
+}
+
+/// Rows sharing the first sort column are ordered by the second one on
+/// every page, not only within a page.
+#[actix_web::test]
+async fn keyset_walk_orders_by_every_sort_column() {
+    let db = setup_db(true).await;
+    let sort = vec![
+        SortColumn::new("tea_mandatory", SortOrder::Desc),
+        SortColumn::new("title", SortOrder::Desc),
+    ];
+    let expected: Vec<String> = Post::find()
+        .order_by_desc(post::Column::TeaMandatory)
+        .order_by_desc(post::Column::Title)
+        .all(&db)
+        .await
+        .unwrap()
+        .iter()
+        .map(|p| p.id.to_string())
+        .collect();
+    assert_eq!(walk_forward(&db, &sort).await, expected);
+}
+
+/// Rows with a `NULL` sort value are visited once, where the database sorts
+/// them, and a cursor on such a row continues past it.
+#[actix_web::test]
+async fn keyset_walk_visits_null_sort_values() {
+    let db = setup_db(true).await;
+    for order in [SortOrder::Asc, SortOrder::Desc] {
+        let sort = vec![SortColumn::new("homepage", order.clone())];
+        let query = match order {
+            SortOrder::Asc => Post::find().order_by_asc(post::Column::Homepage),
+            SortOrder::Desc => Post::find().order_by_desc(post::Column::Homepage),
+        };
+        let expected: Vec<String> = query
+            .order_by_asc(post::Column::Id)
+            .all(&db)
+            .await
+            .unwrap()
+            .iter()
+            .map(|p| p.id.to_string())
+            .collect();
+        assert_eq!(walk_forward(&db, &sort).await, expected);
+
+        // Step back across the boundary between `NULL`s and values.
+        let boundary = if order == SortOrder::Asc { 199 } else { 849 };
+        let (_, entities) = Post::list(
+            &db,
+            &keyset_params(
+                sort,
+                ActixAdminCursor::Before(expected[boundary + 1].clone()),
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            ids(&entities[1..]),
+            expected[boundary - 99..=boundary].to_vec()
+        );
+    }
+}
+
+fn keyset_sql(homepage: Option<&str>, backend: DbBackend) -> String {
+    let sort = [(
+        post::Column::Homepage,
+        SortOrder::Asc,
+        Value::String(homepage.map(str::to_string)),
+    )];
+    let condition = keyset_condition(
+        &sort,
+        post::Column::Id,
+        Value::Int(Some(7)),
+        &ActixAdminCursor::After("7".to_string()),
+        backend,
+    );
+    let sql = Post::find()
+        .filter(condition)
+        .build(DbBackend::Postgres)
+        .to_string();
+    sql.split_once(" WHERE ").unwrap().1.to_string()
+}
+
+/// Past a value lie the larger values, plus the `NULL`s where they sort
+/// last (Postgres); past a `NULL` lie every value where `NULL`s sort first.
+#[actix_web::test]
+async fn keyset_condition_follows_the_backend_null_ordering() {
+    assert_eq!(
+        keyset_sql(Some("a"), DbBackend::Sqlite),
+        r#""post"."homepage" > 'a' OR ("post"."homepage" = 'a' AND "post"."id" > 7)"#
+    );
+    assert_eq!(
+        keyset_sql(None, DbBackend::Sqlite),
+        r#""post"."homepage" IS NOT NULL OR ("post"."homepage" IS NULL AND "post"."id" > 7)"#
+    );
+    assert_eq!(
+        keyset_sql(Some("a"), DbBackend::Postgres),
+        r#""post"."homepage" > 'a' OR "post"."homepage" IS NULL OR ("post"."homepage" = 'a' AND "post"."id" > 7)"#
+    );
+    assert_eq!(
+        keyset_sql(None, DbBackend::Postgres),
+        r#""post"."homepage" IS NULL AND "post"."id" > 7"#
+    );
+}
+
+/// A cursor pointing at a row of another tenant restarts from the first
+/// page of the tenant instead of continuing after the foreign row.
+#[actix_web::test]
+async fn keyset_cursor_is_scoped_to_the_tenant() {
+    let db = setup_db(true).await;
+    let mut params = keyset_params(
+        vec![SortColumn::new("id", SortOrder::Asc)],
+        ActixAdminCursor::After("10".to_string()),
+    );
+    params.tenant_ref = Some(1);
+    let (_, entities) = SampleWithTenantId::list(&db, &params).await.unwrap();
+    assert_eq!(entities[0].primary_key.as_deref(), Some("1"));
+}
+
+fn build_admin() -> ActixAdminBuilder {
+    let mut post_view_model = ActixAdminViewModel::from(Post);
+    post_view_model.keyset_pagination = true;
+
+    let configuration = ActixAdminConfiguration {
+        enable_csrf: false,
+        hash_password: hash_password_argon2,
+        ..Default::default()
+    };
+    let mut builder = ActixAdminBuilder::new(configuration);
+    builder.add_entity::<Post>(&post_view_model);
+    builder
+}
+
+async fn get_body(db: &DatabaseConnection, uri: &str) -> (u16, String) {
+    let (status, body) = call_admin(build_admin(), db, test::TestRequest::get().uri(uri)).await;
+    (status.as_u16(), body)
+}
+
+#[actix_web::test]
+async fn list_renders_prev_next_links_instead_of_page_numbers() {
+    let db = setup_db(true).await;
+
+    let (status, body) = get_body(&db, "/admin/post/list?entities_per_page=10").await;
+    assert_eq!(status, 200);
+    assert!(body.contains("/admin/post/list?after=10\""), "{body}");
+    assert!(!body.contains("list?before="));
+    assert!(!body.contains("Goto page"));
+
+    let (status, body) = get_body(&db, "/admin/post/list?entities_per_page=10&after=10").await;
+    assert_eq!(status, 200);
+    assert!(body.contains("/admin/post/list?before=11\""), "{body}");
+    assert!(body.contains("/admin/post/list?after=20\""), "{body}");
+}
+
+#[actix_web::test]
+async fn list_rejects_unparseable_cursor() {
+    let db = setup_db(false).await;
+    let (status, _) = get_body(&db, "/admin/post/list?after=not-a-number").await;
+    assert_eq!(status, 400);
+}
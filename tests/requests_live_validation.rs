@@ -0,0 +1,115 @@
+//! Integration tests for the live per-field `/validate` route.
+
+mod test_setup;
+
+use actix_web::http::StatusCode;
+use actix_web::test;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use serde::Serialize;
+
+use test_setup::prelude::*;
+use test_setup::{Comment, Post};
+
+#[derive(Serialize)]
+struct CommentForm {
+    insert_date: &'static str,
+    comment: &'static str,
+    user: &'static str,
+    my_decimal: &'static str,
+    post_id: &'static str,
+}
+
+#[actix_web::test]
+async fn form_fields_post_to_the_validate_route() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/create")).await;
+    assert!(
+        body.contains("hx-post=\"/admin/post/validate?field=title\""),
+        "{body}"
+    );
+    // Re-rendering would drop a typed password or a chosen file.
+    assert!(!body.contains("validate?field=api_secret"), "{body}");
+    assert!(!body.contains("validate?field=cover_image"), "{body}");
+    assert!(!body.contains("validate?field=external_id"), "{body}");
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/edit/1")).await;
+    assert!(
+        body.contains("hx-post=\"/admin/post/validate/1?field=title\""),
+        "{body}"
+    );
+}
+
+#[actix_web::test]
+async fn validate_renders_only_the_field_with_its_error() {
+    let db = setup_db(false).await;
+    let uri = "/admin/post/validate?field=title";
+
+    let (status, body) = post_form(
+        &db,
+        uri,
+        PostForm {
+            text: "",
+            ..PostForm::new("a")
+        },
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("name=\"title\""), "{body}");
+    assert!(body.contains("Must be at least 2 characters"), "{body}");
+    // The empty `text` is an error too, but not of the requested field.
+    assert!(!body.contains("name=\"text\""), "{body}");
+    assert!(!body.contains("Cannot be empty"), "{body}");
+
+    let (status, body) = post_form(
+        &db,
+        uri,
+        PostForm {
+            text: "",
+            ..PostForm::new("ab")
+        },
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(!body.contains("Must be at least"), "{body}");
+    assert_eq!(Post::find().count(&db).await.unwrap(), 0);
+
+    let (status, _) = post_form(
+        &db,
+        "/admin/post/validate?field=unknown",
+        PostForm {
+            text: "",
+            ..PostForm::new("ab")
+        },
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+async fn validate_runs_the_async_validators() {
+    let db = setup_db(true).await;
+    let form = || CommentForm {
+        insert_date: "1977-04-01T14:00",
+        comment: "Test 1",
+        user: "test",
+        my_decimal: "200",
+        post_id: "1",
+    };
+    let comments = Comment::find().count(&db).await.unwrap();
+
+    let (_, body) = post_form(&db, "/admin/comment/validate?field=comment", form()).await;
+    assert!(body.contains("The post already has this comment"), "{body}");
+
+    // An edit of the comment itself doesn't conflict.
+    let (_, body) = post_form(&db, "/admin/comment/validate/1?field=comment", form()).await;
+    assert!(
+        !body.contains("The post already has this comment"),
+        "{body}"
+    );
+    assert!(
+        body.contains("hx-post=\"/admin/comment/validate/1?field=comment\""),
+        "{body}"
+    );
+    assert_eq!(Comment::find().count(&db).await.unwrap(), comments);
+}
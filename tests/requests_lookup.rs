@@ -0,0 +1,90 @@
+//! Integration tests for the paginated tom-select lookup (`/<entity>/search`).
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+use serde_json::Value;
+
+use test_setup::prelude::*;
+use test_setup::Post;
+
+async fn get_json(db: &DatabaseConnection, uri: &str) -> Value {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    assert_eq!(status.as_u16(), 200);
+    serde_json::from_str(&body).unwrap()
+}
+
+fn values(json: &Value) -> Vec<&str> {
+    json["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["value"].as_str().unwrap())
+        .collect()
+}
+
+#[actix_web::test]
+async fn lookup_is_paginated() {
+    let db = setup_db(true).await;
+
+    let json = get_json(&db, "/admin/post/search?q=Test+1").await;
+    assert_eq!(json["items"].as_array().unwrap().len(), 20);
+    assert_eq!(json["items"][0]["value"], "1");
+    assert_eq!(json["items"][0]["label"], "Test 1");
+    assert_eq!(json["next_offset"], 20);
+
+    let json = get_json(&db, "/admin/post/search?q=Test+1&offset=20&limit=5").await;
+    assert_eq!(values(&json), vec!["109", "110", "111", "112", "113"]);
+    assert_eq!(json["next_offset"], 25);
+
+    // The last page has no next offset.
+    let json = get_json(&db, "/admin/post/search?q=Test+15").await;
+    assert_eq!(json["items"].as_array().unwrap().len(), 11);
+    assert!(json["next_offset"].is_null());
+
+    // The limit is capped.
+    let json = get_json(&db, "/admin/post/search?limit=5000").await;
+    assert_eq!(json["items"].as_array().unwrap().len(), 100);
+    assert_eq!(json["next_offset"], 100);
+}
+
+#[actix_web::test]
+async fn lookup_matches_its_own_fields() {
+    let db = setup_db(true).await;
+
+    // `external_id` is a lookup field but not searchable on the list page.
+    let json = get_json(&db, "/admin/post/search?q=EXT-0001").await;
+    assert_eq!(
+        values(&json),
+        vec!["10", "11", "12", "13", "14", "15", "16", "17", "18", "19"]
+    );
+    assert_eq!(json["items"][0]["label"], "Test 10");
+
+    let view_model = ActixAdminViewModel::from(Post);
+    let query = ListQuery::from_query("search=EXT-0001", &view_model);
+    let (_, entities) = Post::list(&db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    assert!(entities.is_empty());
+}
+
+#[actix_web::test]
+async fn lookup_labels_default_to_display() {
+    let db = setup_db(true).await;
+
+    // Comment has no `lookup_label`, so whole models are loaded and
+    // labelled by their `Display` impl.
+    let json = get_json(&db, "/admin/comment/search?limit=3").await;
+    assert_eq!(values(&json), vec!["1", "2", "3"]);
+    assert!(
+        json["items"][0]["label"]
+            .as_str()
+            .unwrap()
+            .ends_with(" me@home.com"),
+        "{json}"
+    );
+    assert_eq!(json["next_offset"], 3);
+}
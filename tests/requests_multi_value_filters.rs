@@ -0,0 +1,114 @@
+//! Integration tests for filters on several values (`filter_x=a&filter_x=b`).
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::ListQuery;
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+use test_setup::{Comment, Post};
+
+async fn get_body(db: &DatabaseConnection, uri: &str) -> String {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    assert_eq!(status.as_u16(), 200);
+    body
+}
+
+async fn count<E: ActixAdminViewModelTrait>(
+    db: &DatabaseConnection,
+    view_model: &ActixAdminViewModel,
+    filters: &str,
+) -> usize {
+    let query = ListQuery::from_query(&format!("entities_per_page=1000&{filters}"), view_model);
+    let (_, entities) = E::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities.len()
+}
+
+#[actix_web::test]
+async fn multi_value_callbacks_receive_every_value() {
+    let db = setup_db(true).await;
+    let comment = ActixAdminViewModel::from(Comment);
+
+    assert_eq!(
+        count::<Comment>(
+            &db,
+            &comment,
+            "filter_Posts=3&filter_Posts=5&filter_Posts=7"
+        )
+        .await,
+        3
+    );
+    assert_eq!(count::<Comment>(&db, &comment, "filter_Posts=3").await, 1);
+    assert_eq!(count::<Comment>(&db, &comment, "filter_Posts=").await, 999);
+}
+
+#[actix_web::test]
+async fn generated_filters_match_any_of_several_values() {
+    let db = setup_db(true).await;
+    let post = ActixAdminViewModel::from(Post);
+    let comment = ActixAdminViewModel::from(Comment);
+
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_title=Test+15&filter_title=Test+16&filter_title__op=equals"
+        )
+        .await,
+        2
+    );
+    assert_eq!(
+        count::<Post>(
+            &db,
+            &post,
+            "filter_title=Test+15&filter_title=Test+16&filter_title__op=not_equals"
+        )
+        .await,
+        997
+    );
+    assert_eq!(
+        count::<Comment>(&db, &comment, "filter_post_id=3&filter_post_id=5").await,
+        2
+    );
+}
+
+#[actix_web::test]
+async fn sidebar_renders_a_multi_select() {
+    let db = setup_db(true).await;
+
+    let body = get_body(
+        &db,
+        "/admin/post/list?filter_tea_optional=EverydayTea&filter_tea_optional=BreakfastTea",
+    )
+    .await;
+    assert!(
+        body.contains("name=\"filter_tea_optional\" id=\"filter_tea_optional\" multiple"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<option value=\"EverydayTea\" selected>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<option value=\"BreakfastTea\" selected>"),
+        "{body}"
+    );
+
+    let body = get_body(&db, "/admin/comment/list?filter_Posts=2&filter_Posts=4").await;
+    assert!(
+        body.contains("<option value=\"2\" selected>Test 2</option>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<option value=\"4\" selected>Test 4</option>"),
+        "{body}"
+    );
+    assert!(
+        body.contains("<option value=\"3\">Test 3</option>"),
+        "{body}"
+    );
+}
@@ -35,7 +35,7 @@ use test_setup::{Comment, Post};
 impl ActixAdminBulkActionDispatch for Post {
     async fn run_bulk_action(
         name: &str,
-        _db: &sea_orm::DatabaseConnection,
+        _db: &sea_orm::DatabaseTransaction,
         ids: Vec<Self::Id>,
         _tenant_ref: Option<i32>,
     ) -> Result<Option<String>, ActixAdminError> {
@@ -61,6 +61,7 @@ fn build_admin(enable_csrf: bool, restrict_perms: bool) -> ActixAdminBuilder {
         custom_css_paths: None,
         custom_js_paths: None,
         enable_csrf,
+        hash_password: hash_password_argon2,
     };
 
     let mut post_view_model = ActixAdminViewModel::from(Post);
@@ -77,6 +78,7 @@ fn build_admin(enable_csrf: bool, restrict_perms: bool) -> ActixAdminBuilder {
     builder.add_entity::<Post>(&post_view_model);
     let comment_view_model = ActixAdminViewModel::from(Comment);
     builder.add_entity::<Comment>(&comment_view_model);
+    add_field_templates(&mut builder);
     builder.add_bulk_action_for_entity::<Post>(ActixAdminBulkAction {
         name: "mark_reviewed".into(),
         label: "Mark selected as reviewed".into(),
@@ -0,0 +1,194 @@
+//! Integration tests for write-only `password` fields.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::EntityTrait;
+
+use test_setup::prelude::*;
+use test_setup::Post;
+
+fn with_secret(api_secret: &'static str, api_secret_confirmation: &'static str) -> PostForm {
+    PostForm {
+        api_secret: Some(api_secret),
+        api_secret_confirmation: Some(api_secret_confirmation),
+        ..PostForm::new("password")
+    }
+}
+
+#[actix_web::test]
+async fn password_fields_are_detected_and_hidden_from_the_list() {
+    let view_model = ActixAdminViewModel::from(Post);
+    let field = view_model
+        .fields
+        .iter()
+        .find(|f| f.field_name == "api_secret")
+        .unwrap();
+
+    assert_eq!(field.field_type, ActixAdminViewModelFieldType::Password);
+    assert!(field.list_hide_column);
+}
+
+#[actix_web::test]
+async fn entered_passwords_are_stored_hashed() {
+    let db = setup_db(false).await;
+
+    let (status, _) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        with_secret("hunter2", "hunter2"),
+    )
+    .await;
+    assert!(status.is_redirection());
+    let post = Post::find().one(&db).await.unwrap().unwrap();
+    let hash = post.api_secret.unwrap();
+    assert!(hash.starts_with("$argon2id$"), "{hash}");
+    assert!(verify_password_argon2("hunter2", &hash));
+
+    // Optional password fields may be left empty on create.
+    let (status, _) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        with_secret("", ""),
+    )
+    .await;
+    assert!(status.is_redirection());
+    let post = Post::find_by_id(2).one(&db).await.unwrap().unwrap();
+    assert_eq!(post.api_secret, None);
+}
+
+#[actix_web::test]
+async fn mismatched_confirmation_is_rejected() {
+    let db = setup_db(false).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        with_secret("hunter2", "hunter3"),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Passwords do not match"), "{body}");
+    assert!(
+        body.contains("is-danger\" type=\"password\" value=\"\" name=\"api_secret\""),
+        "{body}"
+    );
+    assert!(!body.contains("hunter"), "{body}");
+    assert!(Post::find().one(&db).await.unwrap().is_none());
+}
+
+#[actix_web::test]
+async fn constraints_apply_to_the_entered_password_not_its_hash() {
+    let db = setup_db(false).await;
+
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        with_secret("short", "short"),
+    )
+    .await;
+    assert!(!status.is_redirection());
+    assert!(body.contains("Must be at least 6 characters"), "{body}");
+
+    // The hash is longer than `max_length`.
+    let (status, body) = post_form(
+        &db,
+        "/admin/post/create_post_from_plaintext",
+        with_secret("long enough", "long enough"),
+    )
+    .await;
+    assert!(status.is_redirection(), "{body}");
+    let post = Post::find().one(&db).await.unwrap().unwrap();
+    assert!(verify_password_argon2(
+        "long enough",
+        &post.api_secret.unwrap()
+    ));
+}
+
+#[actix_web::test]
+async fn empty_password_keeps_the_stored_one_on_edit() {
+    let db = setup_db(true).await;
+
+    let (status, _) = post_form(
+        &db,
+        "/admin/post/edit_post_from_plaintext/1",
+        with_secret("", ""),
+    )
+    .await;
+    assert!(status.is_redirection());
+    let post = Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+    assert_eq!(post.title, "password");
+    assert_eq!(post.api_secret.as_deref(), Some("stored-secret-1"));
+
+    let (status, _) = post_form(
+        &db,
+        "/admin/post/edit_post_from_plaintext/1",
+        with_secret("correct horse", "correct horse"),
+    )
+    .await;
+    assert!(status.is_redirection());
+    let post = Post::find_by_id(1).one(&db).await.unwrap().unwrap();
+    assert!(verify_password_argon2(
+        "correct horse",
+        &post.api_secret.unwrap()
+    ));
+}
+
+#[actix_web::test]
+async fn stored_passwords_are_never_rendered() {
+    let db = setup_db(true).await;
+
+    let (_, body) = call(&db, test::TestRequest::get().uri("/admin/post/edit/1")).await;
+    assert!(
+        body.contains("type=\"password\" value=\"\" name=\"api_secret\""),
+        "{body}"
+    );
+    assert!(body.contains("name=\"api_secret_confirmation\""), "{body}");
+    assert!(
+        body.contains("Leave empty to keep the current password"),
+        "{body}"
+    );
+
+    for uri in [
+        "/admin/post/edit/1",
+        "/admin/post/show/1",
+        "/admin/post/list?entities_per_page=5",
+        "/admin/post/export_csv?entities_per_page=5",
+    ] {
+        let (_, body) = call(&db, test::TestRequest::get().uri(uri)).await;
+        assert!(!body.contains("stored-secret"), "{uri}: {body}");
+    }
+
+    let model = ActixAdminModel::from(Post::find_by_id(1).one(&db).await.unwrap().unwrap());
+    assert_eq!(model.values.get("api_secret").unwrap(), "");
+}
+
+#[actix_web::test]
+async fn passwords_cannot_be_searched_filtered_or_sorted_on() {
+    let db = setup_db(true).await;
+    let list = |query: &str| test::TestRequest::get().uri(&format!("/admin/post/list?{query}"));
+
+    let (status, body) = call(&db, list("search=api_secret%3Astored")).await;
+    assert_eq!(status.as_u16(), 200);
+    assert!(body.contains("Unknown search field `api_secret`"), "{body}");
+    assert!(!body.contains("/admin/post/show/1\""), "{body}");
+
+    let group = r#"{"rules":[{"field":"api_secret","operator":"contains","value":"stored"}]}"#;
+    let (status, body) = call(&db, list(&format!("where={}", urlencoding::encode(group)))).await;
+    assert_eq!(status.as_u16(), 200);
+    assert!(body.contains("Unknown filter field `api_secret`"), "{body}");
+
+    for query in ["sort_by=api_secret", "sort=-api_secret"] {
+        let (status, _) = call(&db, list(query)).await;
+        assert_eq!(status.as_u16(), 400, "{query}");
+    }
+
+    // The query builder doesn't offer the field either.
+    let (_, body) = call(&db, list("entities_per_page=5")).await;
+    assert!(
+        body.contains("&quot;name&quot;:&quot;title&quot;"),
+        "{body}"
+    );
+    assert!(!body.contains("&quot;api_secret&quot;"), "{body}");
+}
@@ -0,0 +1,164 @@
+//! Integration tests for the pluggable list / tom-select search backends.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_web::test;
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbBackend, EntityTrait, QueryFilter, QueryOrder,
+    QueryTrait,
+};
+
+use test_setup::prelude::*;
+use test_setup::{post, Post};
+
+fn fts5_backend() -> ActixAdminSearchBackend {
+    ActixAdminSearchBackend::SqliteFts5 {
+        table: "post_fts".to_string(),
+    }
+}
+
+/// Seed the posts and mirror their searchable columns into an FTS5 table
+/// keyed by the post id.
+async fn setup_fts_db() -> DatabaseConnection {
+    let db = setup_db(true).await;
+    db.execute_unprepared(
+        "CREATE VIRTUAL TABLE post_fts USING fts5(title, text);
+         INSERT INTO post_fts (rowid, title, text) SELECT id, title, text FROM post;
+         UPDATE post SET title = 'Test 15 15 15' WHERE id = 159;
+         UPDATE post_fts SET title = 'Test 15 15 15' WHERE rowid = 159;",
+    )
+    .await
+    .unwrap();
+    db
+}
+
+fn search_params(search: &str, search_by_relevance: bool) -> ActixAdminViewModelParams {
+    ActixAdminViewModelParams {
+        page: Some(1),
+        entities_per_page: Some(50),
+        viewmodel_filter: Vec::new(),
+        filter_group: None,
+        search: search.to_string(),
+        search_exclude: Vec::new(),
+        sort_by: "id".to_string(),
+        sort_order: SortOrder::Asc,
+        sort: Vec::new(),
+        cursor: None,
+        search_backend: fts5_backend(),
+        search_by_relevance,
+        computed_sort_exprs: Default::default(),
+        tenant_ref: None,
+    }
+}
+
+fn ids(entities: &[ActixAdminModel]) -> Vec<String> {
+    entities
+        .iter()
+        .map(|e| e.primary_key.clone().unwrap())
+        .collect()
+}
+
+#[actix_web::test]
+async fn sqlite_fts5_matches_word_prefixes() {
+    let db = setup_fts_db().await;
+
+    let (_, entities) = Post::list(&db, &search_params("test 15", false))
+        .await
+        .unwrap();
+    let expected: Vec<String> = [15, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159]
+        .iter()
+        .map(|i| i.to_string())
+        .collect();
+    assert_eq!(ids(&entities), expected);
+
+    // FTS5 operators in user input are matched literally instead of
+    // raising a syntax error.
+    let (_, entities) = Post::list(&db, &search_params("\"15 OR -", false))
+        .await
+        .unwrap();
+    assert!(entities.is_empty());
+
+    // A blank term does not filter.
+    let (num_pages, _) = Post::list(&db, &search_params("  ", false)).await.unwrap();
+    assert_eq!(num_pages, Some(20));
+}
+
+#[actix_web::test]
+async fn sqlite_fts5_orders_by_relevance() {
+    let db = setup_fts_db().await;
+
+    let (_, entities) = Post::list(&db, &search_params("15", true)).await.unwrap();
+    assert_eq!(entities.len(), 11);
+    assert_eq!(entities[0].primary_key.as_deref(), Some("159"));
+}
+
+#[actix_web::test]
+async fn postgres_backend_builds_websearch_query() {
+    let backend = ActixAdminSearchBackend::Postgres {
+        config: "english".to_string(),
+        tsvector_column: None,
+    };
+    let columns = [post::Column::Title, post::Column::Text];
+    let (rank, order) = backend
+        .relevance(&columns, post::Column::Id, "rust -web")
+        .unwrap();
+    let sql = Post::find()
+        .filter(backend.condition(&columns, post::Column::Id, "rust -web"))
+        .order_by(rank, order)
+        .build(DbBackend::Postgres)
+        .to_string();
+    let tsvector =
+        r#"to_tsvector('english'::regconfig, concat_ws(' ', "post"."title", "post"."text"))"#;
+    let tsquery = "websearch_to_tsquery('english'::regconfig, 'rust -web')";
+    assert!(
+        sql.contains(&format!("WHERE {tsvector} @@ {tsquery}")),
+        "{sql}"
+    );
+    assert!(
+        sql.contains(&format!("ORDER BY ts_rank({tsvector}, {tsquery}) DESC")),
+        "{sql}"
+    );
+
+    let backend = ActixAdminSearchBackend::Postgres {
+        config: "simple".to_string(),
+        tsvector_column: Some("search_vector".to_string()),
+    };
+    let sql = Post::find()
+        .filter(backend.condition(&columns, post::Column::Id, "rust"))
+        .build(DbBackend::Postgres)
+        .to_string();
+    assert!(
+        sql.contains(
+            r#"WHERE "post"."search_vector" @@ websearch_to_tsquery('simple'::regconfig, 'rust')"#
+        ),
+        "{sql}"
+    );
+}
+
+#[actix_web::test]
+async fn tom_select_search_uses_entity_backend() {
+    let db = setup_fts_db().await;
+
+    let mut post_view_model = ActixAdminViewModel::from(Post);
+    post_view_model.search_backend = fts5_backend();
+    post_view_model.search_by_relevance = true;
+    let mut builder = ActixAdminBuilder::new(ActixAdminConfiguration {
+        enable_csrf: false,
+        hash_password: hash_password_argon2,
+        ..Default::default()
+    });
+    builder.add_entity::<Post>(&post_view_model);
+
+    let (status, body) = call_admin(
+        builder,
+        &db,
+        test::TestRequest::get().uri("/admin/post/search?q=15"),
+    )
+    .await;
+    assert_eq!(status.as_u16(), 200);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let items = json["items"].as_array().unwrap();
+    assert_eq!(items.len(), 11);
+    assert_eq!(items[0]["value"], "159");
+}
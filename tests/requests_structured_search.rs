@@ -0,0 +1,189 @@
+//! Integration tests for the structured search syntax of the list search box.
+
+mod test_setup;
+
+use actix_admin::prelude::*;
+use actix_admin::routes::{ListQuery, StructuredSearch};
+use actix_web::test;
+use sea_orm::DatabaseConnection;
+
+use test_setup::prelude::*;
+use test_setup::{Comment, Post};
+
+async fn get(db: &DatabaseConnection, uri: &str) -> (u16, String) {
+    let (status, body) = call(db, test::TestRequest::get().uri(uri)).await;
+    (status.as_u16(), body)
+}
+
+async fn list_ids<E: ActixAdminViewModelTrait>(
+    db: &DatabaseConnection,
+    view_model: &ActixAdminViewModel,
+    search: &str,
+) -> Vec<String> {
+    let qs = format!(
+        "entities_per_page=100&search={}",
+        urlencoding::encode(search)
+    );
+    let query = ListQuery::from_query(&qs, view_model);
+    let (_, entities) = E::list(db, &query.to_view_model_params(None, true))
+        .await
+        .unwrap();
+    entities
+        .into_iter()
+        .map(|e| e.primary_key.unwrap())
+        .collect()
+}
+
+#[actix_web::test]
+async fn parses_fields_operators_exclusions_and_free_text() {
+    let view_model = ActixAdminViewModel::from(Post);
+    let search = StructuredSearch::parse(
+        r#"Title:"rust web" insert_date>=2024-01-01 -tea_mandatory:EverydayTea -draft hello "a:b" 10:30"#,
+        &view_model,
+    )
+    .unwrap();
+
+    assert_eq!(search.text, "hello a:b 10:30");
+    assert_eq!(search.exclude, vec!["draft"]);
+    let filters: Vec<_> = search
+        .filters
+        .iter()
+        .map(|f| (f.name.as_str(), f.operator.clone(), f.value.as_deref()))
+        .collect();
+    assert_eq!(
+        filters,
+        vec![
+            (
+                "title",
+                Some(ActixAdminFilterOperator::Contains),
+                Some("rust web")
+            ),
+            (
+                "insert_date",
+                Some(ActixAdminFilterOperator::GreaterEquals),
+                Some("2024-01-01")
+            ),
+            (
+                "tea_mandatory",
+                Some(ActixAdminFilterOperator::NotContains),
+                Some("EverydayTea")
+            ),
+        ]
+    );
+
+    let err = StructuredSearch::parse("title:", &view_model).unwrap_err();
+    assert_eq!(err, "Missing value for `title`");
+}
+
+#[actix_web::test]
+async fn keys_that_are_no_field_are_free_text() {
+    let view_model = ActixAdminViewModel::from(Post);
+    for input in [
+        "Re: meeting",
+        "https://example.com",
+        "colour:red -colour:blue",
+    ] {
+        let search = StructuredSearch::parse(input, &view_model).unwrap();
+        assert!(search.filters.is_empty(), "{input}");
+    }
+
+    let search = StructuredSearch::parse("Re: meeting", &view_model).unwrap();
+    assert_eq!(search.text, "Re: meeting");
+    let search = StructuredSearch::parse("https://example.com", &view_model).unwrap();
+    assert_eq!(search.text, "https://example.com");
+    let search = StructuredSearch::parse("colour:red -colour:blue", &view_model).unwrap();
+    assert_eq!(search.text, "colour:red");
+    assert_eq!(search.exclude, vec!["colour:blue"]);
+}
+
+#[actix_web::test]
+async fn registered_filters_take_precedence_over_fields() {
+    let view_model = ActixAdminViewModel::from(Comment);
+    let search = StructuredSearch::parse("user:me@home.com", &view_model).unwrap();
+    assert_eq!(search.filters[0].name, "User");
+    assert_eq!(search.filters[0].operator, None);
+
+    let err = StructuredSearch::parse("-user:me@home.com", &view_model).unwrap_err();
+    assert_eq!(err, "The filter `User` cannot be negated");
+}
+
+#[actix_web::test]
+async fn structured_terms_filter_the_list() {
+    let db = setup_db(true).await;
+    let post = ActixAdminViewModel::from(Post);
+    let comment = ActixAdminViewModel::from(Comment);
+
+    let ids = list_ids::<Post>(&db, &post, r#"title:"Test 15""#).await;
+    assert_eq!(ids.len(), 11);
+
+    let ids = list_ids::<Post>(&db, &post, "id>=990 -995").await;
+    let expected: Vec<String> = (990..1000)
+        .filter(|i| *i != 995)
+        .map(|i| i.to_string())
+        .collect();
+    assert_eq!(ids, expected);
+
+    let ids = list_ids::<Post>(&db, &post, "id<5 -id=2 Test").await;
+    assert_eq!(ids, vec!["1", "3", "4"]);
+
+    let ids = list_ids::<Comment>(&db, &comment, "user:me@home.com is_visible:true id<=10").await;
+    assert_eq!(ids, vec!["2", "4", "6", "8", "10"]);
+}
+
+#[actix_web::test]
+async fn wildcards_match_literally_and_negations_keep_nulls() {
+    let db = setup_db(true).await;
+    let post = ActixAdminViewModel::from(Post);
+    let first_ten: Vec<String> = (1..=10).map(|i| i.to_string()).collect();
+
+    for search in [
+        "id<=10 title:%",
+        "id<=10 title:Test_1",
+        r"id<=10 title:\",
+        "id<=10 %",
+    ] {
+        assert!(
+            list_ids::<Post>(&db, &post, search).await.is_empty(),
+            "{search}"
+        );
+    }
+    assert_eq!(list_ids::<Post>(&db, &post, "id<=10 -%").await, first_ten);
+    assert_eq!(list_ids::<Post>(&db, &post, "id<=10 -_").await, first_ten);
+
+    // The homepage of every fifth post is NULL, which contains nothing.
+    let ids = list_ids::<Post>(&db, &post, "id<=10 -homepage:example").await;
+    assert_eq!(ids, vec!["5", "10"]);
+}
+
+#[actix_web::test]
+async fn list_reports_search_errors_inline() {
+    let db = setup_db(true).await;
+
+    let (status, body) = get(&db, "/admin/post/list?search=title%3A").await;
+    assert_eq!(status, 200);
+    assert!(body.contains("id=\"search_error\""), "{body}");
+    assert!(body.contains("Missing value for `title`"), "{body}");
+    assert!(!body.contains("/admin/post/show/1\""), "{body}");
+
+    let (status, body) = get(&db, "/admin/post/list?search=id%3Eabc").await;
+    assert_eq!(status, 200);
+    assert!(
+        body.contains("Invalid value `abc` for `id`: expected an integer"),
+        "{body}"
+    );
+
+    let (status, _) = get(&db, "/admin/post/export_csv?search=title%3A").await;
+    assert_eq!(status, 400);
+}
+
+#[actix_web::test]
+async fn quoted_search_round_trips_through_hx_vals() {
+    let db = setup_db(true).await;
+    let (status, body) = get(&db, "/admin/post/list?search=title%3A%22Test+15%22").await;
+    assert_eq!(status, 200);
+    assert!(body.contains("/admin/post/show/150\""), "{body}");
+    assert!(
+        body.contains(r#""search" : "title:\&quot;Test 15\&quot;""#),
+        "{body}"
+    );
+}
@@ -22,18 +22,75 @@ pub struct Model {
     #[serde(skip_deserializing)]
     #[actix_admin(primary_key)]
     pub id: i32,
-    #[actix_admin(searchable)]
+    #[actix_admin(searchable, regex = "[^<>]*")]
     pub comment: String,
     #[sea_orm(column_type = "Text")]
     #[actix_admin(html_input_type = "email")]
     pub user: String,
     #[sea_orm(column_type = "DateTime")]
+    #[actix_admin(filter, min = "1970-01-01T00:00:00")]
     pub insert_date: DateTime,
+    #[actix_admin(filter)]
     pub is_visible: bool,
-    #[actix_admin(select_list = "Post", foreign_key = "Post", use_tom_select_callback)]
+    #[actix_admin(
+        select_list = "Post",
+        foreign_key = "Post",
+        use_tom_select_callback,
+        filter,
+        show_template = "comment/post_link.html"
+    )]
     pub post_id: Option<i32>,
-    #[actix_admin(ceil = 2)]
+    #[actix_admin(ceil = 2, default_value = "100")]
     pub my_decimal: Decimal,
+    #[actix_admin(filter)]
+    pub ratio: Option<f64>,
+    #[actix_admin(filter)]
+    pub reference: Option<Uuid>,
+    #[actix_admin(filter)]
+    pub reminder: Option<Time>,
+    #[actix_admin(
+        filter,
+        one_of = "-3, -2, -1, 0, 1, 2, 3, 4",
+        list_template = "comment/priority.html",
+        form_template = "comment/priority_input.html"
+    )]
+    pub priority: Option<i16>,
+    #[actix_admin(filter, max = 4000000000)]
+    pub views: Option<u32>,
+    #[actix_admin(visible_when = "status in (1, 2)", required_when = "status = 2")]
+    pub review_note: Option<String>,
+    #[actix_admin(select_list = "Status", filter)]
+    pub status: Option<Status>,
+}
+
+/// Stored as an integer, so its select list values differ from the labels.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Deserialize,
+    Serialize,
+    DeriveActixAdminEnumSelectList,
+)]
+#[sea_orm(rs_type = "i32", db_type = "Integer")]
+pub enum Status {
+    #[sea_orm(num_value = 0)]
+    #[actix_admin(label = "Awaiting review")]
+    Pending,
+    #[sea_orm(num_value = 1)]
+    #[actix_admin(label = "Approved")]
+    Published,
+    #[sea_orm(num_value = 2)]
+    Rejected,
+}
+
+impl Display for Status {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -67,6 +124,45 @@ impl ActixAdminModelValidationTrait<ActiveModel> for Entity {
     }
 }
 
+// A post must not get the same comment twice.
+#[async_trait(?Send)]
+impl ActixAdminModelAsyncValidationTrait for Entity {
+    async fn validate_async(
+        db: &DatabaseConnection,
+        _session: &Session,
+        operation: &ActixAdminOperation,
+        model: &ActixAdminModel,
+    ) -> HashMap<String, String> {
+        let mut errors = HashMap::new();
+        let (Some(comment), Some(post_id)) = (
+            model.values.get("comment"),
+            model
+                .values
+                .get("post_id")
+                .and_then(|id| id.parse::<i32>().ok()),
+        ) else {
+            return errors;
+        };
+
+        let mut query = Entity::find()
+            .filter(Column::Comment.eq(comment.as_str()))
+            .filter(Column::PostId.eq(post_id));
+        if let ActixAdminOperation::Edit(id) = operation {
+            query = query.filter(Column::Id.ne(id.parse::<i32>().unwrap_or_default()));
+        }
+        if query.count(db).await.unwrap_or_default() > 0 {
+            errors.insert(
+                "comment".to_string(),
+                "The post already has this comment".to_string(),
+            );
+        }
+        errors
+    }
+}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
+#[async_trait]
 impl ActixAdminModelFilterTrait<Entity> for Entity {
     fn get_filter() -> Vec<ActixAdminModelFilter<Entity>> {
         vec![
@@ -98,8 +194,53 @@ impl ActixAdminModelFilterTrait<Entity> for Entity {
                     }
                 },
             ),
+            ActixAdminModelFilter::with_range(
+                "Inserted",
+                ActixAdminModelFilterType::DateTimeRange,
+                |q: sea_orm::Select<Entity>, range| -> sea_orm::Select<Entity> {
+                    let range = range.unwrap_or_default();
+                    q.apply_if(range.from, |query, from| {
+                        query.filter(Column::InsertDate.gte(from))
+                    })
+                    .apply_if(range.to, |query, to| {
+                        query.filter(Column::InsertDate.lt(to))
+                    })
+                },
+            ),
+            ActixAdminModelFilter::with_multi(
+                "Posts",
+                ActixAdminModelFilterType::MultiSelectList,
+                |q: sea_orm::Select<Entity>, ids| -> sea_orm::Select<Entity> {
+                    let ids: Vec<i32> = ids.iter().filter_map(|id| id.parse().ok()).collect();
+                    if ids.is_empty() {
+                        q
+                    } else {
+                        q.filter(Column::PostId.is_in(ids))
+                    }
+                },
+            ),
         ]
     }
+
+    async fn get_filter_values(
+        filter: &ActixAdminModelFilter<Entity>,
+        db: &DatabaseConnection,
+    ) -> Option<Vec<(String, String)>> {
+        match filter.name.as_str() {
+            "Posts" => Post::find()
+                .order_by_asc(post::Column::Id)
+                .all(db)
+                .await
+                .ok()
+                .map(|posts| {
+                    posts
+                        .into_iter()
+                        .map(|post| (post.id.to_string(), post.title))
+                        .collect()
+                }),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Model {
@@ -1,17 +1,21 @@
+use super::sample_with_tenant_id;
+use super::SampleWithTenantId;
+use super::{comment, create_tables, post, Comment, Post};
 use actix_admin::prelude::*;
 use actix_session::Session;
+use actix_web::body::to_bytes;
+use actix_web::http::header::ContentType;
+use actix_web::http::StatusCode;
 use actix_web::web;
 use actix_web::web::Bytes;
 use actix_web::Error;
 use actix_web::HttpRequest;
 use actix_web::HttpResponse;
-use chrono::Local;
-use sea_orm::prelude::Decimal;
+use actix_web::{test, App};
+use chrono::{Local, NaiveTime};
+use sea_orm::prelude::{Decimal, Uuid};
 use sea_orm::{ConnectOptions, DatabaseConnection, EntityTrait, Set};
-
-use super::sample_with_tenant_id;
-use super::SampleWithTenantId;
-use super::{comment, create_tables, post, Comment, Post};
+use serde::Serialize;
 
 pub async fn setup_db(create_entities: bool) -> DatabaseConnection {
     let opt = ConnectOptions::new("sqlite::memory:".to_owned());
@@ -57,6 +61,14 @@ pub async fn setup_db(create_entities: bool) -> DatabaseConnection {
                     Some(format!("# markdown-{}", i))
                 }),
                 external_id: Set(Some(format!("EXT-{:05}", i))),
+                settings: Set(if i % 5 == 0 {
+                    None
+                } else {
+                    Some(
+                        serde_json::json!({ "theme": "light", "retries": i % 4, "tags": ["<b>", "x"] }),
+                    )
+                }),
+                api_secret: Set(Some(format!("stored-secret-{}", i))),
                 ..Default::default()
             };
             let insert_res = Post::insert(row)
@@ -71,6 +83,16 @@ pub async fn setup_db(create_entities: bool) -> DatabaseConnection {
                 insert_date: Set(Local::now().naive_utc()),
                 is_visible: Set(i % 2 == 0),
                 post_id: Set(Some(insert_res.last_insert_id as i32)),
+                ratio: Set(Some(i as f64 / 4.0)),
+                reference: Set(Some(Uuid::from_u128(i as u128))),
+                reminder: Set(NaiveTime::from_hms_opt(i as u32 % 24, 30, 0)),
+                priority: Set(Some((i % 5) as i16)),
+                views: Set(Some(i as u32 * 10)),
+                status: Set(match i % 3 {
+                    0 => Some(comment::Status::Pending),
+                    1 => Some(comment::Status::Published),
+                    _ => None,
+                }),
                 ..Default::default()
             };
             let _res = Comment::insert(row)
@@ -132,6 +154,22 @@ macro_rules! create_server (
     });
 );
 
+/// Register the partials named by the test entities' `*_template` attributes.
+pub fn add_field_templates(admin_builder: &mut ActixAdminBuilder) {
+    admin_builder.add_field_template(
+        "comment/priority.html",
+        r#"{% if value %}<span class="tag priority-{{ value }}">P{{ value }}</span>{% endif %}"#,
+    );
+    admin_builder.add_field_template(
+        "comment/priority_input.html",
+        r#"<input type="range" min="0" max="4" name="{{ field.field_name }}" value="{{ value }}">"#,
+    );
+    admin_builder.add_field_template(
+        "comment/post_link.html",
+        r#"{% if fk_value %}<a href="{{ base_path }}/post/show/{{ value }}">{{ fk_value }}</a>{% endif %}"#,
+    );
+}
+
 pub fn create_actix_admin_builder(
     enable_auth: bool,
     tenant_ref: Option<for<'a> fn(&'a Session) -> Option<i32>>,
@@ -139,6 +177,9 @@ pub fn create_actix_admin_builder(
 ) -> ActixAdminBuilder {
     let mut post_view_model = ActixAdminViewModel::from(Post);
     post_view_model.inline_edit = enable_inline_editing;
+    post_view_model.collapsible_fieldsets = vec!["Advanced".to_string()];
+    post_view_model.non_copyable_fields = vec!["external_id".to_string()];
+    post_view_model.duplicate_copies_files = true;
     let comment_view_model = ActixAdminViewModel::from(Comment);
     let sample_with_tenant_id_view_model = ActixAdminViewModel::from(SampleWithTenantId);
 
@@ -154,6 +195,7 @@ pub fn create_actix_admin_builder(
         custom_css_paths: None,
         custom_js_paths: None,
         enable_csrf: false,
+        hash_password: hash_password_argon2,
     };
 
     let mut admin_builder = ActixAdminBuilder::new(configuration);
@@ -161,6 +203,8 @@ pub fn create_actix_admin_builder(
     admin_builder.add_entity::<Comment>(&comment_view_model);
     admin_builder.add_entity::<SampleWithTenantId>(&sample_with_tenant_id_view_model);
 
+    add_field_templates(&mut admin_builder);
+
     admin_builder.add_custom_handler_for_entity::<Comment>(
         "Create Comment From Plaintext",
         "/create_post_from_plaintext",
@@ -209,6 +253,85 @@ pub fn create_actix_admin_builder(
     admin_builder
 }
 
+/// Send `req` to the admin of [`create_actix_admin_builder`] and return the
+/// response status and body.
+pub async fn call(db: &DatabaseConnection, req: test::TestRequest) -> (StatusCode, String) {
+    call_admin(create_actix_admin_builder(false, None, false), db, req).await
+}
+
+/// [`call`] with an admin the test has configured itself.
+pub async fn call_admin(
+    builder: ActixAdminBuilder,
+    db: &DatabaseConnection,
+    req: test::TestRequest,
+) -> (StatusCode, String) {
+    let actix_admin = builder.get_actix_admin();
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(actix_admin))
+            .app_data(web::Data::new(db.clone()))
+            .service(builder.get_scope()),
+    )
+    .await;
+    let resp = test::call_service(&app, req.to_request()).await;
+    let status = resp.status();
+    let body = to_bytes(resp.into_body()).await.unwrap();
+    (status, String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Post `form` url-encoded to `uri`, see [`call`].
+pub async fn post_form(
+    db: &DatabaseConnection,
+    uri: &str,
+    form: impl Serialize,
+) -> (StatusCode, String) {
+    call(
+        db,
+        test::TestRequest::post()
+            .insert_header(ContentType::form_url_encoded())
+            .uri(uri)
+            .set_form(form),
+    )
+    .await
+}
+
+/// The create/edit form of a post with its mandatory fields filled in. The
+/// optional fields are only submitted when set.
+#[derive(Clone, Serialize)]
+pub struct PostForm {
+    pub title: &'static str,
+    pub text: &'static str,
+    pub tea_mandatory: &'static str,
+    pub insert_date: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_secret: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_secret_confirmation: Option<&'static str>,
+}
+
+impl PostForm {
+    #[allow(dead_code)]
+    pub fn new(title: &'static str) -> Self {
+        PostForm {
+            title,
+            text: "some content",
+            tea_mandatory: "EverydayTea",
+            insert_date: "2024-01-31",
+            homepage: None,
+            contact_email: None,
+            settings: None,
+            api_secret: None,
+            api_secret_confirmation: None,
+        }
+    }
+}
+
 async fn create_post_from_plaintext<E: ActixAdminViewModelTrait>(
     session: Session,
     req: HttpRequest,
@@ -14,7 +14,10 @@ pub use sample_with_tenant_id::Entity as SampleWithTenantId;
 #[allow(unused_imports)]
 pub mod prelude {
     pub use super::*;
-    pub use crate::test_setup::helper::{create_actix_admin_builder, setup_db, BodyTest};
+    pub use crate::test_setup::helper::{
+        add_field_templates, call, call_admin, create_actix_admin_builder, post_form, setup_db,
+        BodyTest, PostForm,
+    };
     pub use crate::test_setup::webdriver::{setup, teardown, wait_for_url_contains};
 }
 
@@ -49,6 +52,8 @@ pub async fn create_tables(db: &DbConn) -> Result<ExecResult, DbErr> {
         .col(ColumnDef::new(post::Column::CoverImage).string())
         .col(ColumnDef::new(post::Column::NotesMd).string())
         .col(ColumnDef::new(post::Column::ExternalId).string())
+        .col(ColumnDef::new(post::Column::Settings).json())
+        .col(ColumnDef::new(post::Column::ApiSecret).string())
         .to_owned();
 
     let _result = create_table(db, &stmt).await;
@@ -81,6 +86,13 @@ pub async fn create_tables(db: &DbConn) -> Result<ExecResult, DbErr> {
                 .not_null(),
         )
         .col(ColumnDef::new(comment::Column::PostId).integer())
+        .col(ColumnDef::new(comment::Column::Ratio).double())
+        .col(ColumnDef::new(comment::Column::Reference).uuid())
+        .col(ColumnDef::new(comment::Column::Reminder).time())
+        .col(ColumnDef::new(comment::Column::Priority).small_integer())
+        .col(ColumnDef::new(comment::Column::Views).unsigned())
+        .col(ColumnDef::new(comment::Column::ReviewNote).string())
+        .col(ColumnDef::new(comment::Column::Status).integer())
         .foreign_key(
             ForeignKeyCreateStatement::new()
                 .name("fk-comment-post")
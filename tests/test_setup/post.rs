@@ -1,9 +1,11 @@
 use actix_admin::prelude::*;
 use sea_orm::entity::prelude::*;
+use sea_orm::DatabaseTransaction;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::sync::Mutex;
 
 #[derive(
     Clone,
@@ -23,33 +25,59 @@ pub struct Model {
     #[serde(skip_deserializing)]
     #[actix_admin(primary_key)]
     pub id: i32,
-    #[actix_admin(searchable)]
+    #[actix_admin(
+        searchable,
+        filter,
+        lookup,
+        lookup_label,
+        min_length = 2,
+        max_length = 100
+    )]
     pub title: String,
     #[sea_orm(column_type = "Text")]
     #[actix_admin(shorten = "100", searchable, textarea)]
     pub text: String,
     #[actix_admin(select_list = "Tea")]
     pub tea_mandatory: Tea,
-    #[actix_admin(select_list = "Tea")]
+    #[actix_admin(select_list = "Tea", filter)]
     pub tea_optional: Option<Tea>,
+    #[actix_admin(filter)]
     pub insert_date: Date,
 
     // New nullable columns — one per new field type — used by
     // integration tests to verify list/show rendering.
     #[actix_admin(html_render)]
     pub summary_html: Option<String>,
-    #[actix_admin(url)]
+    #[actix_admin(url, tab = "Details", fieldset = "Contact", form_row = "contact")]
     pub homepage: Option<String>,
-    #[actix_admin(email)]
+    #[actix_admin(email, tab = "Details", fieldset = "Contact", form_row = "contact")]
     pub contact_email: Option<String>,
-    #[actix_admin(image)]
+    #[actix_admin(image, tab = "Details", fieldset = "Media")]
     pub cover_image: Option<String>,
     #[actix_admin(wysiwyg)]
     pub notes_md: Option<String>,
-    #[actix_admin(readonly)]
+    #[actix_admin(readonly, lookup)]
     pub external_id: Option<String>,
+    #[actix_admin(
+        json_schema = "SETTINGS_SCHEMA",
+        tab = "Details",
+        fieldset = "Advanced"
+    )]
+    pub settings: Option<Json>,
+    #[actix_admin(password, min_length = 6, max_length = 64)]
+    pub api_secret: Option<String>,
 }
 
+pub const SETTINGS_SCHEMA: &str = r#"{
+    "type": "object",
+    "required": ["theme"],
+    "properties": {
+        "theme": { "enum": ["light", "dark"] },
+        "retries": { "type": "integer", "minimum": 0 },
+        "tags": { "type": "array", "items": { "type": "string" } }
+    }
+}"#;
+
 impl Display for Model {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match &*self {
@@ -105,4 +133,132 @@ impl FromStr for Tea {
 
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
-impl ActixAdminModelFilterTrait<Entity> for Entity {}
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+/// Every hook call as `"<hook> <id> <title>"`, checked by the hook tests.
+pub static HOOK_CALLS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record_hook(hook: &str, id: &str, model: &ActixAdminModel) {
+    let title = model.values.get("title").map(String::as_str);
+    HOOK_CALLS
+        .lock()
+        .unwrap()
+        .push(format!("{hook} {id} {}", title.unwrap_or_default()));
+}
+
+// Vetoes the title "vetoed" and upper-cases a title prefixed with "shout:".
+fn check_title(new: &mut ActixAdminModel) -> Result<(), ActixAdminError> {
+    match new.values.get_mut("title") {
+        Some(title) if title == "vetoed" => Err(ActixAdminError::new(
+            ActixAdminErrorType::Forbidden,
+            "Vetoed by hook",
+        )),
+        Some(title) => {
+            if let Some(shout) = title.strip_prefix("shout:") {
+                *title = shout.to_uppercase();
+            }
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+// Fails once a post titled "rollback" is written, after the write itself.
+fn fail_rollback(model: &ActixAdminModel) -> Result<(), ActixAdminError> {
+    match model.values.get("title") {
+        Some(title) if title == "rollback" => Err(ActixAdminError::new(
+            ActixAdminErrorType::Forbidden,
+            "Rolled back by hook",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[async_trait(?Send)]
+impl ActixAdminModelHooksTrait for Entity {
+    async fn before_create(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        new: &mut ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        record_hook("before_create", "", new);
+        check_title(new)
+    }
+
+    async fn after_create(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        id: &str,
+        new: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        record_hook("after_create", id, new);
+        fail_rollback(new)
+    }
+
+    async fn before_update(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        id: &str,
+        old: &ActixAdminModel,
+        new: &mut ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        record_hook("before_update", id, old);
+        check_title(new)
+    }
+
+    async fn after_update(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        id: &str,
+        _old: &ActixAdminModel,
+        new: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        record_hook("after_update", id, new);
+        fail_rollback(new)
+    }
+
+    // A post titled "protected" can't be deleted.
+    async fn before_delete(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        id: &str,
+        old: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        record_hook("before_delete", id, old);
+        match old.values.get("title") {
+            Some(title) if title == "protected" => Err(ActixAdminError::new(
+                ActixAdminErrorType::Forbidden,
+                "Protected by hook",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    async fn after_delete(
+        _db: &DatabaseTransaction,
+        _session: &Session,
+        id: &str,
+        old: &ActixAdminModel,
+    ) -> Result<(), ActixAdminError> {
+        record_hook("after_delete", id, old);
+        fail_rollback(old)
+    }
+}
+
+impl ActixAdminModelFilterTrait<Entity> for Entity {
+    fn get_filter() -> Vec<ActixAdminModelFilter<Entity>> {
+        vec![ActixAdminModelFilter::with_range(
+            "Inserted",
+            ActixAdminModelFilterType::DateRange,
+            |q: sea_orm::Select<Entity>, range| -> sea_orm::Select<Entity> {
+                let range = range.unwrap_or_default();
+                q.apply_if(range.from, |query, from| {
+                    query.filter(Column::InsertDate.gte(from.date()))
+                })
+                .apply_if(range.to, |query, to| {
+                    query.filter(Column::InsertDate.lt(to.date()))
+                })
+            },
+        )]
+    }
+}
@@ -46,4 +46,8 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl ActixAdminModelValidationTrait<ActiveModel> for Entity {}
 
+impl ActixAdminModelAsyncValidationTrait for Entity {}
+
+impl ActixAdminModelHooksTrait for Entity {}
+
 impl ActixAdminModelFilterTrait<Entity> for Entity {}